@@ -0,0 +1,45 @@
+//! Partial hex-prefix lookup over captured `AccountId32` sets.
+//!
+//! Borrowed from the "partial node ID" idea in CLI peer tooling: an operator
+//! who only logged the first few bytes of a collator key (from a truncated
+//! log line, say) shouldn't need the full 32-byte key to find it again in a
+//! set already pulled from storage (e.g. [`ChainClient::get_aura_authorities`](crate::chain_client::ChainClient::get_aura_authorities)'s
+//! output, or a captured set of balance/account records).
+
+use subxt::utils::AccountId32;
+
+/// Find every account in `accounts` whose bytes start with `prefix`, a
+/// (possibly `0x`-prefixed, possibly odd-length) hex string. An odd-length
+/// prefix matches on the high nibble only of its final byte. Returns no
+/// matches if `prefix` isn't valid hex or is longer than a full account (64
+/// hex digits).
+pub fn find_by_prefix<'a>(accounts: &'a [AccountId32], prefix: &str) -> Vec<&'a AccountId32> {
+    let prefix = prefix.strip_prefix("0x").unwrap_or(prefix);
+    let Some(nibbles) = hex_nibbles(prefix) else {
+        return Vec::new();
+    };
+    if nibbles.len() > 64 {
+        return Vec::new();
+    }
+
+    accounts.iter().filter(|account| matches_prefix(&account.0, &nibbles)).collect()
+}
+
+fn hex_nibbles(s: &str) -> Option<Vec<u8>> {
+    s.chars().map(|c| c.to_digit(16).map(|d| d as u8)).collect()
+}
+
+fn matches_prefix(bytes: &[u8; 32], nibbles: &[u8]) -> bool {
+    for (i, chunk) in nibbles.chunks(2).enumerate() {
+        let byte = bytes[i];
+        if chunk[0] != (byte >> 4) {
+            return false;
+        }
+        if let Some(&low_nibble) = chunk.get(1) {
+            if low_nibble != (byte & 0x0F) {
+                return false;
+            }
+        }
+    }
+    true
+}
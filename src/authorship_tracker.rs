@@ -0,0 +1,265 @@
+//! Live authorship tracker for a registered set of accounts.
+//!
+//! Replaces the O(1000) backward RPC walk `ChainClient::get_last_authored_block_time`
+//! used to do on every call (one full-block fetch plus an extrinsic decode and a
+//! storage read per block, every time someone asked "when did this collator last
+//! author?"). Instead, one long-lived task per chain follows `subscribe_finalized()`
+//! and keeps an in-memory last-authored (block number, wall-clock time) per watched
+//! account, so a lookup is O(1) regardless of how long the monitor has been running.
+//!
+//! A bounded backward scan primes the map for whichever accounts are registered
+//! when a chain's tracking task starts, matching the old per-call walk's search
+//! limit, so a freshly-watched account isn't reported as "never seen" just because
+//! it authored before this process started.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use futures::StreamExt;
+use subxt::utils::AccountId32;
+use tokio::sync::{watch, RwLock};
+use tracing::{debug, info, warn};
+
+use crate::chain_client::ChainClient;
+use crate::config::{Network, SystemChain};
+
+/// How many blocks to scan backward on startup to prime a newly-watched
+/// chain, matching the old per-call walk's search limit.
+const PRIMING_SCAN_LIMIT: u32 = 1000;
+
+/// A watched account's most recently observed authored block.
+#[derive(Debug, Clone, Copy)]
+struct Authored {
+    block_number: u64,
+    /// Wall-clock time (time since `UNIX_EPOCH`) the block was authored,
+    /// taken from its `Timestamp::set` inherent.
+    authored_at: Duration,
+}
+
+/// Long-lived subsystem tracking last-authored-block info for a registered
+/// set of accounts per chain, fed by a live `subscribe_finalized()` stream
+/// instead of being recomputed from scratch on every lookup.
+pub struct AuthorshipTracker {
+    /// chain_name -> account -> last-authored info
+    data: Arc<RwLock<HashMap<String, HashMap<AccountId32, Authored>>>>,
+    /// chain_name -> accounts currently registered for tracking, so `data`
+    /// stays bounded to what callers actually care about.
+    watched: Arc<RwLock<HashMap<String, HashSet<AccountId32>>>>,
+    /// Shutdown signal - a `watch` channel so each chain's tracker task can
+    /// `select!` on it concurrently with the block subscription.
+    shutdown: watch::Sender<bool>,
+}
+
+impl AuthorshipTracker {
+    pub fn new() -> Self {
+        Self {
+            data: Arc::new(RwLock::new(HashMap::new())),
+            watched: Arc::new(RwLock::new(HashMap::new())),
+            shutdown: watch::Sender::new(false),
+        }
+    }
+
+    /// Register `account` for live authorship tracking on `chain_name`,
+    /// spawning that chain's tracking task the first time any account is
+    /// registered for it.
+    pub async fn watch_account(
+        self: &Arc<Self>,
+        rpc_url: String,
+        network: Network,
+        chain: SystemChain,
+        account: AccountId32,
+    ) {
+        let chain_name = chain.display_name(network);
+        let newly_tracked_chain = {
+            let mut watched = self.watched.write().await;
+            let set = watched.entry(chain_name.clone()).or_default();
+            let was_empty = set.is_empty();
+            set.insert(account);
+            was_empty
+        };
+
+        if newly_tracked_chain {
+            let tracker = Arc::clone(self);
+            tokio::spawn(async move {
+                tracker.run_chain_tracker(chain_name, rpc_url, network, chain).await;
+            });
+        }
+    }
+
+    /// Deregister `account` from `chain_name`, dropping its entry so the map
+    /// stays bounded to whatever's still being watched.
+    pub async fn unwatch_account(&self, chain_name: &str, account: &AccountId32) {
+        if let Some(set) = self.watched.write().await.get_mut(chain_name) {
+            set.remove(account);
+        }
+        if let Some(chain_data) = self.data.write().await.get_mut(chain_name) {
+            chain_data.remove(account);
+        }
+    }
+
+    /// How long ago `account` last authored a block on `chain_name`, served
+    /// as an O(1) lookup against the live-updated map. `None` if `account`
+    /// isn't registered via [`watch_account`](Self::watch_account), or
+    /// hasn't authored anything the tracker has seen.
+    pub async fn get_last_authored_block_time(
+        &self,
+        chain_name: &str,
+        account: &AccountId32,
+    ) -> Option<Duration> {
+        let data = self.data.read().await;
+        let authored = data.get(chain_name)?.get(account)?;
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        Some(now.saturating_sub(authored.authored_at))
+    }
+
+    /// Height of the last block `account` authored on `chain_name`, if any.
+    pub async fn get_last_authored_block_number(
+        &self,
+        chain_name: &str,
+        account: &AccountId32,
+    ) -> Option<u64> {
+        let data = self.data.read().await;
+        data.get(chain_name)?.get(account).map(|a| a.block_number)
+    }
+
+    /// Signal every chain's tracking task to stop.
+    pub fn shutdown(&self) {
+        let _ = self.shutdown.send(true);
+    }
+
+    async fn record_authored(
+        &self,
+        chain_name: &str,
+        account: AccountId32,
+        block_number: u64,
+        authored_at: Duration,
+    ) {
+        let mut data = self.data.write().await;
+        let chain_data = data.entry(chain_name.to_string()).or_default();
+        chain_data.insert(account, Authored { block_number, authored_at });
+    }
+
+    /// Connects to `chain_name`, primes the map with one bounded backward
+    /// scan for whichever accounts are registered at startup, then follows
+    /// `subscribe_finalized()` incrementally until shutdown.
+    async fn run_chain_tracker(
+        &self,
+        chain_name: String,
+        rpc_url: String,
+        network: Network,
+        chain: SystemChain,
+    ) {
+        let mut shutdown_rx = self.shutdown.subscribe();
+
+        let client = match ChainClient::connect(&rpc_url, network, chain).await {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("AuthorshipTracker: failed to connect to {}: {}", chain_name, e);
+                return;
+            }
+        };
+
+        if let Err(e) = self.prime_from_backward_scan(&chain_name, &client).await {
+            warn!("AuthorshipTracker: priming scan for {} failed: {}", chain_name, e);
+        }
+
+        let mut blocks_sub = match client.api().blocks().subscribe_finalized().await {
+            Ok(sub) => sub,
+            Err(e) => {
+                warn!(
+                    "AuthorshipTracker: failed to subscribe to finalized blocks on {}: {}",
+                    chain_name, e
+                );
+                return;
+            }
+        };
+
+        info!("AuthorshipTracker: live tracking started for {}", chain_name);
+
+        loop {
+            tokio::select! {
+                _ = shutdown_rx.changed() => {
+                    debug!("AuthorshipTracker: stopping tracker for {}", chain_name);
+                    break;
+                }
+                maybe_block = blocks_sub.next() => {
+                    let Some(block_result) = maybe_block else {
+                        warn!("AuthorshipTracker: finalized block stream for {} ended", chain_name);
+                        break;
+                    };
+                    let block = match block_result {
+                        Ok(b) => b,
+                        Err(e) => {
+                            warn!("AuthorshipTracker: error reading finalized block on {}: {}", chain_name, e);
+                            continue;
+                        }
+                    };
+
+                    let watched = self.watched.read().await.get(&chain_name).cloned().unwrap_or_default();
+                    if watched.is_empty() {
+                        continue;
+                    }
+
+                    let Ok(Some(author)) = client.get_block_author(&block).await else {
+                        continue;
+                    };
+                    if !watched.contains(&author) {
+                        continue;
+                    }
+
+                    let Ok(timestamp_ms) = client.get_block_timestamp(&block).await else {
+                        continue;
+                    };
+                    self.record_authored(
+                        &chain_name,
+                        author,
+                        block.number() as u64,
+                        Duration::from_millis(timestamp_ms),
+                    ).await;
+                }
+            }
+        }
+    }
+
+    /// One-time bounded backward scan (same `PRIMING_SCAN_LIMIT` the old
+    /// per-call walk used) so a newly-watched account's last-authored info
+    /// isn't blank until it next produces a block.
+    async fn prime_from_backward_scan(&self, chain_name: &str, client: &ChainClient) -> anyhow::Result<()> {
+        let mut remaining: HashSet<AccountId32> =
+            self.watched.read().await.get(chain_name).cloned().unwrap_or_default();
+        if remaining.is_empty() {
+            return Ok(());
+        }
+
+        let current_block = client.api().blocks().at_latest().await?;
+        let mut current_hash = current_block.hash();
+        let mut blocks_checked = 0u32;
+
+        while blocks_checked < PRIMING_SCAN_LIMIT && !remaining.is_empty() {
+            let block = client.api().blocks().at(current_hash).await?;
+
+            if let Some(author) = client.get_block_author(&block).await? {
+                if remaining.remove(&author) {
+                    let timestamp_ms = client.get_block_timestamp(&block).await?;
+                    self.record_authored(
+                        chain_name,
+                        author,
+                        block.number() as u64,
+                        Duration::from_millis(timestamp_ms),
+                    )
+                    .await;
+                }
+            }
+
+            let header = block.header();
+            if header.number == 0 {
+                break;
+            }
+            current_hash = header.parent_hash;
+            blocks_checked += 1;
+        }
+
+        Ok(())
+    }
+}
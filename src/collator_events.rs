@@ -0,0 +1,318 @@
+//! Real-time `CollatorSelection` event subscription.
+//!
+//! `get_candidates`/`get_invulnerables` only reflect current storage - the only
+//! way to learn a watched collator was evicted or undercut is to re-poll them
+//! on the next check cycle. This instead follows finalized blocks' events for
+//! the `CollatorSelection` pallet and emits a typed [`CollatorEvent`] the
+//! moment one affecting a watched account lands, via the same `broadcast`
+//! fan-out [`BlockTracker`](crate::block_tracker::BlockTracker) uses for
+//! session rotations, so a monitoring loop can react (e.g. re-bond via
+//! `take_candidate_slot_via_proxy`) immediately instead of a polling interval
+//! later. Event fields are decoded with the same dynamic `ScaleValue` helpers
+//! already used for storage decoding.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use futures::StreamExt;
+use subxt::ext::scale_value::{Composite, Primitive, Value as ScaleValue, ValueDef};
+use subxt::utils::AccountId32;
+use tokio::sync::{broadcast, watch, RwLock};
+use tracing::{debug, info, warn};
+
+use crate::chain_client::ChainClient;
+use crate::config::{Network, SystemChain};
+
+/// The `CollatorSelection` pallet event variants we care about, with their
+/// fields resolved to the watched account(s) involved.
+#[derive(Debug, Clone)]
+pub enum CollatorEvent {
+    CandidateAdded { chain_name: String, account: AccountId32, deposit: u128 },
+    CandidateBondUpdated { chain_name: String, account: AccountId32, deposit: u128 },
+    CandidateRemoved { chain_name: String, account: AccountId32 },
+    /// A candidate slot changed hands - `evicted` lost it, `new_candidate` now
+    /// holds it at `deposit`. Either side may be the watched account: if
+    /// `evicted` is ours, we were undercut; if `new_candidate` is ours, our
+    /// own `take_candidate_slot_via_proxy` call just landed.
+    CandidateReplaced {
+        chain_name: String,
+        evicted: AccountId32,
+        new_candidate: AccountId32,
+        deposit: u128,
+    },
+    InvulnerableAdded { chain_name: String, account: AccountId32 },
+    InvulnerableRemoved { chain_name: String, account: AccountId32 },
+}
+
+/// Long-lived subsystem that watches a registered set of accounts per chain
+/// for `CollatorSelection` events, broadcasting a [`CollatorEvent`] the moment
+/// one lands rather than waiting for the next storage poll.
+pub struct CollatorEventWatcher {
+    /// chain_name -> accounts currently registered for event filtering.
+    watched: Arc<RwLock<HashMap<String, HashSet<AccountId32>>>>,
+    events_tx: broadcast::Sender<CollatorEvent>,
+    /// Shutdown signal - a `watch` channel so each chain's watcher task can
+    /// `select!` on it concurrently with the block subscription.
+    shutdown: watch::Sender<bool>,
+}
+
+impl CollatorEventWatcher {
+    pub fn new() -> Self {
+        Self {
+            watched: Arc::new(RwLock::new(HashMap::new())),
+            events_tx: broadcast::channel(64).0,
+            shutdown: watch::Sender::new(false),
+        }
+    }
+
+    /// Subscribe to the event stream across all tracked chains. Lagging or
+    /// absent receivers simply miss old events rather than blocking senders.
+    pub fn subscribe(&self) -> broadcast::Receiver<CollatorEvent> {
+        self.events_tx.subscribe()
+    }
+
+    /// Register `account` for event filtering on `chain_name`, spawning that
+    /// chain's watcher task the first time any account is registered for it.
+    pub async fn watch_account(
+        self: &Arc<Self>,
+        rpc_url: String,
+        network: Network,
+        chain: SystemChain,
+        account: AccountId32,
+    ) {
+        let chain_name = chain.display_name(network);
+        let newly_tracked_chain = {
+            let mut watched = self.watched.write().await;
+            let set = watched.entry(chain_name.clone()).or_default();
+            let was_empty = set.is_empty();
+            set.insert(account);
+            was_empty
+        };
+
+        if newly_tracked_chain {
+            let watcher = Arc::clone(self);
+            tokio::spawn(async move {
+                watcher.run_chain_watcher(chain_name, rpc_url, network, chain).await;
+            });
+        }
+    }
+
+    /// Deregister `account` from `chain_name`, so filtering (and memory) stays
+    /// bounded to whatever's still being watched.
+    pub async fn unwatch_account(&self, chain_name: &str, account: &AccountId32) {
+        if let Some(set) = self.watched.write().await.get_mut(chain_name) {
+            set.remove(account);
+        }
+    }
+
+    /// Signal every chain's watcher task to stop.
+    pub fn shutdown(&self) {
+        let _ = self.shutdown.send(true);
+    }
+
+    async fn run_chain_watcher(
+        &self,
+        chain_name: String,
+        rpc_url: String,
+        network: Network,
+        chain: SystemChain,
+    ) {
+        let mut shutdown_rx = self.shutdown.subscribe();
+
+        let client = match ChainClient::connect(&rpc_url, network, chain).await {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("CollatorEventWatcher: failed to connect to {}: {}", chain_name, e);
+                return;
+            }
+        };
+
+        let mut blocks_sub = match client.api().blocks().subscribe_finalized().await {
+            Ok(sub) => sub,
+            Err(e) => {
+                warn!(
+                    "CollatorEventWatcher: failed to subscribe to finalized blocks on {}: {}",
+                    chain_name, e
+                );
+                return;
+            }
+        };
+
+        info!("CollatorEventWatcher: live event tracking started for {}", chain_name);
+
+        loop {
+            tokio::select! {
+                _ = shutdown_rx.changed() => {
+                    debug!("CollatorEventWatcher: stopping watcher for {}", chain_name);
+                    break;
+                }
+                maybe_block = blocks_sub.next() => {
+                    let Some(block_result) = maybe_block else {
+                        warn!("CollatorEventWatcher: finalized block stream for {} ended", chain_name);
+                        break;
+                    };
+                    let block = match block_result {
+                        Ok(b) => b,
+                        Err(e) => {
+                            warn!("CollatorEventWatcher: error reading finalized block on {}: {}", chain_name, e);
+                            continue;
+                        }
+                    };
+
+                    let watched = self.watched.read().await.get(&chain_name).cloned().unwrap_or_default();
+                    if watched.is_empty() {
+                        continue;
+                    }
+
+                    let events = match block.events().await {
+                        Ok(events) => events,
+                        Err(e) => {
+                            warn!("CollatorEventWatcher: failed to fetch events on {}: {}", chain_name, e);
+                            continue;
+                        }
+                    };
+
+                    for event in events.iter() {
+                        let event = match event {
+                            Ok(e) => e,
+                            Err(e) => {
+                                warn!("CollatorEventWatcher: error decoding event on {}: {}", chain_name, e);
+                                continue;
+                            }
+                        };
+
+                        if event.pallet_name() != "CollatorSelection" {
+                            continue;
+                        }
+
+                        let fields = match event.field_values() {
+                            Ok(fields) => fields,
+                            Err(e) => {
+                                warn!("CollatorEventWatcher: failed to decode event fields on {}: {}", chain_name, e);
+                                continue;
+                            }
+                        };
+
+                        if let Some(collator_event) = decode_collator_event(&chain_name, event.variant_name(), &fields, &watched) {
+                            let _ = self.events_tx.send(collator_event);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Pull the field at `name` (struct variant) or `index` (tuple variant) out
+/// of an event's decoded fields - the pallet's events are struct variants on
+/// current runtimes, but this tolerates either shape.
+fn field<'a>(fields: &'a Composite<u32>, name: &str, index: usize) -> Option<&'a ScaleValue<u32>> {
+    match fields {
+        Composite::Named(items) => items.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+        Composite::Unnamed(items) => items.get(index),
+    }
+}
+
+/// Decode one `CollatorSelection` event into a [`CollatorEvent`] iff it
+/// involves an account in `watched`.
+fn decode_collator_event(
+    chain_name: &str,
+    variant: &str,
+    fields: &Composite<u32>,
+    watched: &HashSet<AccountId32>,
+) -> Option<CollatorEvent> {
+    match variant {
+        "CandidateAdded" => {
+            let account = parse_account_id(field(fields, "account_id", 0)?).ok()?;
+            if !watched.contains(&account) {
+                return None;
+            }
+            let deposit = parse_u128(field(fields, "deposit", 1)?).ok()?;
+            Some(CollatorEvent::CandidateAdded { chain_name: chain_name.to_string(), account, deposit })
+        }
+        "CandidateBondUpdated" => {
+            let account = parse_account_id(field(fields, "account_id", 0)?).ok()?;
+            if !watched.contains(&account) {
+                return None;
+            }
+            let deposit = parse_u128(field(fields, "deposit", 1)?).ok()?;
+            Some(CollatorEvent::CandidateBondUpdated { chain_name: chain_name.to_string(), account, deposit })
+        }
+        "CandidateRemoved" => {
+            let account = parse_account_id(field(fields, "account_id", 0)?).ok()?;
+            if !watched.contains(&account) {
+                return None;
+            }
+            Some(CollatorEvent::CandidateRemoved { chain_name: chain_name.to_string(), account })
+        }
+        "CandidateReplaced" | "CandidateSlotReplaced" => {
+            let evicted = parse_account_id(field(fields, "old", 0)?).ok()?;
+            let new_candidate = parse_account_id(field(fields, "new", 1)?).ok()?;
+            if !watched.contains(&evicted) && !watched.contains(&new_candidate) {
+                return None;
+            }
+            let deposit = parse_u128(field(fields, "deposit", 2)?).ok()?;
+            Some(CollatorEvent::CandidateReplaced {
+                chain_name: chain_name.to_string(),
+                evicted,
+                new_candidate,
+                deposit,
+            })
+        }
+        "InvulnerableAdded" => {
+            let account = parse_account_id(field(fields, "account_id", 0)?).ok()?;
+            if !watched.contains(&account) {
+                return None;
+            }
+            Some(CollatorEvent::InvulnerableAdded { chain_name: chain_name.to_string(), account })
+        }
+        "InvulnerableRemoved" => {
+            let account = parse_account_id(field(fields, "account_id", 0)?).ok()?;
+            if !watched.contains(&account) {
+                return None;
+            }
+            Some(CollatorEvent::InvulnerableRemoved { chain_name: chain_name.to_string(), account })
+        }
+        _ => None,
+    }
+}
+
+/// Same account-decoding logic as `chain_client`'s storage-decode path
+/// (32-byte account, possibly newtype-wrapped) - duplicated narrowly here
+/// since `chain_client::parse_account_id` takes the generic `T` its own
+/// storage call sites use and isn't exported.
+fn parse_account_id<T: std::fmt::Debug>(value: &ScaleValue<T>) -> anyhow::Result<AccountId32> {
+    match &value.value {
+        ValueDef::Composite(Composite::Unnamed(items)) => {
+            if items.len() == 1 {
+                return parse_account_id(&items[0]);
+            } else if items.len() == 32 {
+                let mut bytes = [0u8; 32];
+                for (i, item) in items.iter().enumerate() {
+                    match &item.value {
+                        ValueDef::Primitive(Primitive::U128(n)) => bytes[i] = *n as u8,
+                        _ => return Err(anyhow::anyhow!("expected u8 in account bytes")),
+                    }
+                }
+                return Ok(AccountId32(bytes));
+            }
+        }
+        ValueDef::Composite(Composite::Named(items)) => {
+            for (name, item) in items {
+                if name == "0" {
+                    return parse_account_id(item);
+                }
+            }
+        }
+        _ => {}
+    }
+    Err(anyhow::anyhow!("failed to parse AccountId32 from event field: {:?}", value))
+}
+
+fn parse_u128<T: std::fmt::Debug>(value: &ScaleValue<T>) -> anyhow::Result<u128> {
+    match &value.value {
+        ValueDef::Primitive(Primitive::U128(n)) => Ok(*n),
+        ValueDef::Composite(Composite::Unnamed(items)) if items.len() == 1 => parse_u128(&items[0]),
+        _ => Err(anyhow::anyhow!("failed to parse u128 from event field: {:?}", value)),
+    }
+}
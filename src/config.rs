@@ -3,12 +3,20 @@
 use serde::Deserialize;
 use std::collections::HashMap;
 
-/// Network type (Polkadot or Kusama ecosystem)
+use crate::error::CollatorError;
+
+/// Network type. Polkadot and Kusama are the production networks; Westend,
+/// Paseo and Rococo are test networks that run the same system-chain
+/// pallets and are where runtime upgrades land first, so the author
+/// grabber is useful there before it's trusted against production.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Network {
     Polkadot,
     Kusama,
+    Westend,
+    Paseo,
+    Rococo,
 }
 
 impl Network {
@@ -17,14 +25,20 @@ impl Network {
         match self {
             Network::Polkadot => 10, // DOT has 10 decimals
             Network::Kusama => 12,   // KSM has 12 decimals
+            Network::Westend => 12,  // WND has 12 decimals
+            Network::Paseo => 10,    // PAS mirrors DOT's 10 decimals
+            Network::Rococo => 12,   // ROC has 12 decimals
         }
     }
 
-    /// Get the reserve amount to keep (1 DOT or 0.1 KSM)
+    /// Get the reserve amount to keep (1 DOT / 0.1 KSM, or the test-token equivalent)
     pub fn reserve_amount(&self) -> u128 {
         match self {
             Network::Polkadot => 1 * 10u128.pow(10),  // 1 DOT
             Network::Kusama => 10u128.pow(11),        // 0.1 KSM
+            Network::Westend => 1 * 10u128.pow(12),   // 1 WND
+            Network::Paseo => 1 * 10u128.pow(10),     // 1 PAS
+            Network::Rococo => 1 * 10u128.pow(12),     // 1 ROC
         }
     }
 
@@ -33,11 +47,26 @@ impl Network {
         match self {
             Network::Polkadot => "DOT",
             Network::Kusama => "KSM",
+            Network::Westend => "WND",
+            Network::Paseo => "PAS",
+            Network::Rococo => "ROC",
+        }
+    }
+
+    /// SS58 address prefix for this network - see `crate::ss58`. Westend,
+    /// Paseo and Rococo all use the generic substrate prefix rather than
+    /// their own.
+    pub fn ss58_prefix(&self) -> u16 {
+        match self {
+            Network::Polkadot => 0,
+            Network::Kusama => 2,
+            Network::Westend | Network::Paseo | Network::Rococo => 42,
         }
     }
 }
 
-/// Chain identifier for system chains
+/// Chain identifier for system chains. Glutton is a test-only stress-test
+/// parachain (no balances/proxy pallets) that only exists on Westend/Rococo.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum SystemChain {
@@ -47,18 +76,20 @@ pub enum SystemChain {
     Coretime,
     People,
     Encointer,
+    Glutton,
 }
 
 impl SystemChain {
-    /// Get the network this chain belongs to
+    /// Get the networks this chain is deployed on
     pub fn valid_networks(&self) -> Vec<Network> {
         match self {
-            SystemChain::AssetHub => vec![Network::Polkadot, Network::Kusama],
-            SystemChain::BridgeHub => vec![Network::Polkadot, Network::Kusama],
-            SystemChain::Collectives => vec![Network::Polkadot], // Only on Polkadot
-            SystemChain::Coretime => vec![Network::Polkadot, Network::Kusama],
-            SystemChain::People => vec![Network::Polkadot, Network::Kusama],
+            SystemChain::AssetHub => vec![Network::Polkadot, Network::Kusama, Network::Westend, Network::Paseo, Network::Rococo],
+            SystemChain::BridgeHub => vec![Network::Polkadot, Network::Kusama, Network::Westend, Network::Paseo, Network::Rococo],
+            SystemChain::Collectives => vec![Network::Polkadot, Network::Westend, Network::Rococo], // Not on Kusama or Paseo
+            SystemChain::Coretime => vec![Network::Polkadot, Network::Kusama, Network::Westend, Network::Paseo, Network::Rococo],
+            SystemChain::People => vec![Network::Polkadot, Network::Kusama, Network::Westend, Network::Paseo, Network::Rococo],
             SystemChain::Encointer => vec![Network::Kusama], // Only on Kusama
+            SystemChain::Glutton => vec![Network::Westend, Network::Rococo], // Test-only stress-test chain
         }
     }
 
@@ -71,6 +102,7 @@ impl SystemChain {
             SystemChain::Coretime => "Coretime",
             SystemChain::People => "People",
             SystemChain::Encointer => "Encointer",
+            SystemChain::Glutton => "Glutton",
         };
         format!("{} {}", network.symbol(), chain_name)
     }
@@ -115,6 +147,31 @@ fn default_enabled() -> bool {
     true
 }
 
+/// The `chains` map key a given `(network, chain)` pair is looked up under,
+/// e.g. `"polkadot_assethub"`. Shared between `AppConfig::chain_config` and
+/// `AppConfigBuilder::chain` so the two never drift apart.
+fn chain_key(network: Network, chain: SystemChain) -> String {
+    format!(
+        "{}_{}",
+        match network {
+            Network::Polkadot => "polkadot",
+            Network::Kusama => "kusama",
+            Network::Westend => "westend",
+            Network::Paseo => "paseo",
+            Network::Rococo => "rococo",
+        },
+        match chain {
+            SystemChain::AssetHub => "assethub",
+            SystemChain::BridgeHub => "bridgehub",
+            SystemChain::Collectives => "collectives",
+            SystemChain::Coretime => "coretime",
+            SystemChain::People => "people",
+            SystemChain::Encointer => "encointer",
+            SystemChain::Glutton => "glutton",
+        }
+    )
+}
+
 /// Main application configuration
 #[derive(Debug, Clone, Deserialize)]
 pub struct AppConfig {
@@ -124,10 +181,55 @@ pub struct AppConfig {
     /// Collator account address for Kusama chains (SS58 format)
     pub kusama_collator_address: String,
 
-    /// Proxy account seed (hex or mnemonic)
-    /// This is the account that will sign transactions on behalf of the collator
-    /// The proxy should be configured as NonTransfer type
-    pub proxy_seed: String,
+    /// Collator account address for Westend chains (SS58 format), so
+    /// registration/bond-update/proxy-signing flows can be rehearsed on a
+    /// testnet before pointing them at DOT/KSM funds. `None` skips Westend
+    /// chains entirely.
+    pub westend_collator_address: Option<String>,
+
+    /// Collator account address for Paseo chains (SS58 format) - see
+    /// `westend_collator_address`.
+    pub paseo_collator_address: Option<String>,
+
+    /// Legacy plaintext proxy seed (hex, mnemonic, or URI), kept only for
+    /// migrating existing deployments. If set, it is imported into the
+    /// keystore under `proxy_key_name` on first startup and should then be
+    /// removed from config/env.
+    pub proxy_seed: Option<String>,
+
+    /// Name of the proxy signing key to look up in the keystore.
+    /// This is the account that will sign transactions on behalf of the collator.
+    /// The proxy should be configured as NonTransfer type.
+    #[serde(default = "default_proxy_key_name")]
+    pub proxy_key_name: String,
+
+    /// Directory holding encrypted keystore files (one per imported key).
+    #[serde(default = "default_keystore_dir")]
+    pub keystore_dir: String,
+
+    /// Passphrase used to derive the encryption key for the on-disk keystore.
+    pub keystore_passphrase: String,
+
+    /// URL of an external HTTP/JSON signing endpoint. When set, the proxy key
+    /// never has to be imported into the local keystore: signing payloads are
+    /// forwarded to this endpoint instead, and `proxy_account_id` identifies
+    /// the account it signs on behalf of.
+    pub remote_signer_url: Option<String>,
+
+    /// SS58 address of the proxy account, required when `remote_signer_url` is
+    /// set (there's no local key to derive it from).
+    pub proxy_account_id: Option<String>,
+
+    /// Path to the JSON file that persists each chain's cumulative recorded reward
+    /// total, so a restart resumes from the last observed payout instead of
+    /// re-notifying (or double-counting) rewards already seen.
+    #[serde(default = "default_reward_ledger_path")]
+    pub reward_ledger_path: String,
+
+    /// Directory `--sign-only` writes offline-signing payload files into, one
+    /// per chain/call kind, for an operator to carry to an air-gapped machine.
+    #[serde(default = "default_offline_payload_dir")]
+    pub offline_payload_dir: String,
 
     /// Slack webhook URL for notifications (simpler, but can't update/delete)
     pub slack_webhook_url: Option<String>,
@@ -139,6 +241,34 @@ pub struct AppConfig {
     /// Slack channel ID or name (required when using bot token)
     pub slack_channel: Option<String>,
 
+    /// Discord incoming webhook URL. When set, every alert also fans out to
+    /// this channel alongside Slack.
+    pub discord_webhook_url: Option<String>,
+
+    /// Telegram bot token, used together with `telegram_chat_id` to deliver
+    /// alerts via the Telegram bot API.
+    pub telegram_bot_token: Option<String>,
+
+    /// Telegram chat ID to send alerts to (required when `telegram_bot_token` is set).
+    pub telegram_chat_id: Option<String>,
+
+    /// Generic webhook URL for alert delivery, for integrations that don't have
+    /// a dedicated backend (e.g. an internal dashboard or a custom relay).
+    pub webhook_url: Option<String>,
+
+    /// HMAC-SHA256 signing secret for `webhook_url`, so the receiver can verify
+    /// the payload actually came from this service. Optional even when
+    /// `webhook_url` is set, but strongly recommended for anything internet-facing.
+    pub webhook_signing_secret: Option<String>,
+
+    /// PagerDuty Events API v2 routing key. When set, Critical-severity alerts
+    /// (and only those) trigger a PagerDuty incident.
+    pub pagerduty_routing_key: Option<String>,
+
+    /// Bind address (e.g. "0.0.0.0:9898") for the Prometheus scrape endpoint.
+    /// When unset, no metrics server is started.
+    pub metrics_bind_addr: Option<String>,
+
     /// Slack user IDs to ping for ON-CHAIN actions (registration, bond updates, manual actions)
     /// These are people who can submit transactions
     /// Format: U08CUCTA3R7,U12345ABCD
@@ -149,6 +279,61 @@ pub struct AppConfig {
     /// Format: U08CUCTA3R7,U12345ABCD
     pub slack_user_ids_ops: Vec<String>,
 
+    /// Transaction tip (in the chain's smallest unit) attached to
+    /// `register_as_candidate`/`update_bond` extrinsics, to get a competitive
+    /// re-registration included ahead of a session boundary rather than sitting
+    /// unincluded. Zero by default - most chains don't need one.
+    #[serde(default)]
+    pub tip: u128,
+
+    /// Ceiling a resubmission may escalate `tip` to - see `resubmit_after_blocks`.
+    /// Zero (the default) disables resubmission entirely, regardless of how long
+    /// the transaction sits unincluded.
+    #[serde(default)]
+    pub tip_ceiling: u128,
+
+    /// If a submitted registration/bond-update extrinsic hasn't reached a
+    /// finalized block within this many finalized blocks, resubmit it with the
+    /// tip doubled (capped at `tip_ceiling`) instead of waiting indefinitely.
+    /// Zero disables resubmission.
+    #[serde(default)]
+    pub resubmit_after_blocks: u64,
+
+    /// Estimated Polkadot system chain session length in blocks, used only to
+    /// project "blocks until next rotation" in the periodic Slack summary -
+    /// not read from the chain, since `pallet_session`'s rotation schedule
+    /// isn't exposed generically via dynamic storage. `None` omits the estimate.
+    #[serde(default)]
+    pub polkadot_session_length_blocks: Option<u64>,
+
+    /// Estimated Kusama system chain session length in blocks - see
+    /// `polkadot_session_length_blocks`.
+    #[serde(default)]
+    pub kusama_session_length_blocks: Option<u64>,
+
+    /// Estimated Westend system chain session length in blocks - see
+    /// `polkadot_session_length_blocks`.
+    #[serde(default)]
+    pub westend_session_length_blocks: Option<u64>,
+
+    /// Estimated Paseo system chain session length in blocks - see
+    /// `polkadot_session_length_blocks`.
+    #[serde(default)]
+    pub paseo_session_length_blocks: Option<u64>,
+
+    /// Outbound message-lane backlog (`latest_generated_nonce -
+    /// latest_received_nonce`) a BridgeHub's default lane may reach before
+    /// `alert_bridge_lane_backlog` pages ops. See `bridge_pallet_names` in
+    /// `chain_client` for which chains this applies to.
+    #[serde(default = "default_bridge_lane_backlog_threshold")]
+    pub bridge_lane_backlog_threshold: u64,
+
+    /// How many consecutive check cycles a BridgeHub's recorded
+    /// counterpart-chain best-finalized header may go without advancing
+    /// before its GRANDPA finality relay is treated as stalled.
+    #[serde(default = "default_bridge_relay_stall_cycles")]
+    pub bridge_relay_stall_cycles: u32,
+
     /// Check interval in seconds (for continuous monitoring mode)
     #[serde(default = "default_check_interval")]
     pub check_interval_secs: u64,
@@ -161,6 +346,49 @@ pub struct AppConfig {
     /// Key format: "network_chain" e.g., "polkadot_assethub"
     #[serde(default)]
     pub chains: HashMap<String, ChainConfig>,
+
+    /// The single `(network, chain)` this monitor connects to via the
+    /// embedded light client rather than a plain RPC endpoint - see
+    /// `ChainClient::connect_light`. Same "network_chain" key format as
+    /// `chains`, e.g. "polkadot_assethub". Opt-in and unset by default;
+    /// requires `light_client_relay_spec_path`/`light_client_chain_spec_path`
+    /// to also be set.
+    #[serde(default)]
+    pub light_client_chain_key: Option<String>,
+
+    /// Path to the relay chain's chain spec JSON (from `sync_state_genSyncSpec`
+    /// against a trusted node, fetched once ahead of time) - see
+    /// `light_client_chain_key`.
+    #[serde(default)]
+    pub light_client_relay_spec_path: Option<String>,
+
+    /// Path to the parachain's chain spec JSON - see `light_client_chain_key`.
+    #[serde(default)]
+    pub light_client_chain_spec_path: Option<String>,
+}
+
+fn default_proxy_key_name() -> String {
+    "proxy".to_string()
+}
+
+fn default_keystore_dir() -> String {
+    "keys".to_string()
+}
+
+fn default_reward_ledger_path() -> String {
+    "reward_ledger.json".to_string()
+}
+
+fn default_offline_payload_dir() -> String {
+    "offline-payloads".to_string()
+}
+
+fn default_bridge_lane_backlog_threshold() -> u64 {
+    50
+}
+
+fn default_bridge_relay_stall_cycles() -> u32 {
+    3
 }
 
 fn default_check_interval() -> u64 {
@@ -172,11 +400,29 @@ fn default_summary_interval() -> u64 {
 }
 
 impl AppConfig {
-    /// Get the collator address for a given network
-    pub fn collator_address(&self, network: Network) -> &str {
+    /// Get the collator address for a given network, if one is configured.
+    /// Always `Some` for Polkadot/Kusama; testnets are opt-in via their own
+    /// env var, and Rococo isn't wired up at all (Parity is winding it down
+    /// in favor of Paseo as the primary testnet).
+    pub fn collator_address(&self, network: Network) -> Option<&str> {
+        match network {
+            Network::Polkadot => Some(self.polkadot_collator_address.as_str()),
+            Network::Kusama => Some(self.kusama_collator_address.as_str()),
+            Network::Westend => self.westend_collator_address.as_deref(),
+            Network::Paseo => self.paseo_collator_address.as_deref(),
+            Network::Rococo => None,
+        }
+    }
+
+    /// Get the estimated session length (in blocks) for a given network, if
+    /// configured - see `polkadot_session_length_blocks`.
+    pub fn session_length_blocks(&self, network: Network) -> Option<u64> {
         match network {
-            Network::Polkadot => &self.polkadot_collator_address,
-            Network::Kusama => &self.kusama_collator_address,
+            Network::Polkadot => self.polkadot_session_length_blocks,
+            Network::Kusama => self.kusama_session_length_blocks,
+            Network::Westend => self.westend_session_length_blocks,
+            Network::Paseo => self.paseo_session_length_blocks,
+            Network::Rococo => None,
         }
     }
 
@@ -199,26 +445,38 @@ impl AppConfig {
 
     /// Get chain config for a specific network and chain
     pub fn chain_config(&self, network: Network, chain: SystemChain) -> Option<&ChainConfig> {
-        let key = format!(
-            "{}_{}",
-            match network {
-                Network::Polkadot => "polkadot",
-                Network::Kusama => "kusama",
-            },
-            match chain {
-                SystemChain::AssetHub => "assethub",
-                SystemChain::BridgeHub => "bridgehub",
-                SystemChain::Collectives => "collectives",
-                SystemChain::Coretime => "coretime",
-                SystemChain::People => "people",
-                SystemChain::Encointer => "encointer",
-            }
-        );
-        self.chains.get(&key)
+        self.chains.get(&chain_key(network, chain))
+    }
+
+    /// If `network`/`chain` is the configured light-client target, the
+    /// `(relay_chain_spec_json_path, chain_spec_json_path)` pair
+    /// `ChainClient::connect_light` needs to connect it that way instead of
+    /// over a plain RPC endpoint. `None` if light-client mode isn't
+    /// configured, or is configured for a different chain.
+    pub fn light_client_spec_paths(&self, network: Network, chain: SystemChain) -> Option<(&str, &str)> {
+        if self.light_client_chain_key.as_deref() != Some(chain_key(network, chain).as_str()) {
+            return None;
+        }
+        Some((self.light_client_relay_spec_path.as_deref()?, self.light_client_chain_spec_path.as_deref()?))
+    }
+
+    /// Start building an `AppConfig` programmatically instead of reading it
+    /// from the environment - for embedding this crate as a library (tests,
+    /// bespoke dashboards, multi-tenant runners). See [`AppConfigBuilder`].
+    pub fn builder() -> AppConfigBuilder {
+        AppConfigBuilder::default()
     }
 
     /// Load configuration from environment and config file
     pub fn load() -> anyhow::Result<Self> {
+        Self::from_env()
+    }
+
+    /// Load configuration from the environment/`.env`/`config.toml`, the way
+    /// every existing deployment of this binary runs - layers env vars on
+    /// top of [`AppConfigBuilder`] rather than duplicating its defaults and
+    /// validation. `load()` is a thin alias kept for existing callers.
+    pub fn from_env() -> anyhow::Result<Self> {
         // Load .env file if present - try multiple locations
         // 1. Explicit path from ENV_FILE environment variable
         // 2. Config subdirectory (config/.env) - for service deployment
@@ -239,9 +497,24 @@ impl AppConfig {
             .map_err(|_| anyhow::anyhow!("COLLATOR_POLKADOT_COLLATOR_ADDRESS not set"))?;
         let kusama_address = std::env::var("COLLATOR_KUSAMA_COLLATOR_ADDRESS")
             .map_err(|_| anyhow::anyhow!("COLLATOR_KUSAMA_COLLATOR_ADDRESS not set"))?;
-        let proxy_seed = std::env::var("COLLATOR_PROXY_SEED")
-            .map_err(|_| anyhow::anyhow!("COLLATOR_PROXY_SEED not set"))?;
-        
+        // Testnet collator addresses are opt-in - unset simply skips that network.
+        let westend_address = std::env::var("COLLATOR_WESTEND_COLLATOR_ADDRESS").ok();
+        let paseo_address = std::env::var("COLLATOR_PASEO_COLLATOR_ADDRESS").ok();
+        // Legacy plaintext seed - optional now that keys live in the keystore
+        let proxy_seed = std::env::var("COLLATOR_PROXY_SEED").ok();
+        let proxy_key_name = std::env::var("COLLATOR_PROXY_KEY_NAME")
+            .unwrap_or_else(|_| default_proxy_key_name());
+        let keystore_dir = std::env::var("COLLATOR_KEYSTORE_DIR")
+            .unwrap_or_else(|_| default_keystore_dir());
+        let keystore_passphrase = std::env::var("COLLATOR_KEYSTORE_PASSPHRASE")
+            .map_err(|_| anyhow::anyhow!("COLLATOR_KEYSTORE_PASSPHRASE not set"))?;
+        let remote_signer_url = std::env::var("COLLATOR_REMOTE_SIGNER_URL").ok();
+        let proxy_account_id = std::env::var("COLLATOR_PROXY_ACCOUNT_ID").ok();
+        let reward_ledger_path = std::env::var("COLLATOR_REWARD_LEDGER_PATH")
+            .unwrap_or_else(|_| default_reward_ledger_path());
+        let offline_payload_dir = std::env::var("COLLATOR_OFFLINE_PAYLOAD_DIR")
+            .unwrap_or_else(|_| default_offline_payload_dir());
+
         // Read optional environment variables
         let slack_webhook = std::env::var("COLLATOR_SLACK_WEBHOOK_URL").ok();
         
@@ -257,6 +530,40 @@ impl AppConfig {
             .map(|s| s.split(',').map(|id| id.trim().to_string()).filter(|id| !id.is_empty()).collect())
             .unwrap_or_default();
         
+        let tip = std::env::var("COLLATOR_TIP")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0u128);
+        let tip_ceiling = std::env::var("COLLATOR_TIP_CEILING")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0u128);
+        let resubmit_after_blocks = std::env::var("COLLATOR_RESUBMIT_AFTER_BLOCKS")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or(0u64);
+        let polkadot_session_length_blocks = std::env::var("COLLATOR_POLKADOT_SESSION_LENGTH_BLOCKS")
+            .ok()
+            .and_then(|s| s.parse().ok());
+        let kusama_session_length_blocks = std::env::var("COLLATOR_KUSAMA_SESSION_LENGTH_BLOCKS")
+            .ok()
+            .and_then(|s| s.parse().ok());
+        let westend_session_length_blocks = std::env::var("COLLATOR_WESTEND_SESSION_LENGTH_BLOCKS")
+            .ok()
+            .and_then(|s| s.parse().ok());
+        let paseo_session_length_blocks = std::env::var("COLLATOR_PASEO_SESSION_LENGTH_BLOCKS")
+            .ok()
+            .and_then(|s| s.parse().ok());
+
+        let bridge_lane_backlog_threshold = std::env::var("COLLATOR_BRIDGE_LANE_BACKLOG_THRESHOLD")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(default_bridge_lane_backlog_threshold);
+        let bridge_relay_stall_cycles = std::env::var("COLLATOR_BRIDGE_RELAY_STALL_CYCLES")
+            .ok()
+            .and_then(|s| s.parse().ok())
+            .unwrap_or_else(default_bridge_relay_stall_cycles);
+
         let check_interval = std::env::var("COLLATOR_CHECK_INTERVAL_SECS")
             .ok()
             .and_then(|s| s.parse().ok())
@@ -270,22 +577,111 @@ impl AppConfig {
         let slack_bot_token = std::env::var("COLLATOR_SLACK_BOT_TOKEN").ok();
         let slack_channel = std::env::var("COLLATOR_SLACK_CHANNEL").ok();
 
+        // Additional notification channels (all optional, fan out alongside Slack)
+        let discord_webhook_url = std::env::var("COLLATOR_DISCORD_WEBHOOK_URL").ok();
+        let telegram_bot_token = std::env::var("COLLATOR_TELEGRAM_BOT_TOKEN").ok();
+        let telegram_chat_id = std::env::var("COLLATOR_TELEGRAM_CHAT_ID").ok();
+        let webhook_url = std::env::var("COLLATOR_WEBHOOK_URL").ok();
+        let webhook_signing_secret = std::env::var("COLLATOR_WEBHOOK_SIGNING_SECRET").ok();
+        let pagerduty_routing_key = std::env::var("COLLATOR_PAGERDUTY_ROUTING_KEY").ok();
+        let metrics_bind_addr = std::env::var("COLLATOR_METRICS_BIND_ADDR").ok();
+
+        // Embedded light-client connection mode - opt-in, and only ever for
+        // one (network, chain) at a time.
+        let light_client_chain_key = std::env::var("COLLATOR_LIGHT_CLIENT_CHAIN_KEY").ok();
+        let light_client_relay_spec_path = std::env::var("COLLATOR_LIGHT_CLIENT_RELAY_SPEC_PATH").ok();
+        let light_client_chain_spec_path = std::env::var("COLLATOR_LIGHT_CLIENT_CHAIN_SPEC_PATH").ok();
+
         // Load chain configs from config.toml if present
         let chains = Self::load_chain_configs()?;
 
-        Ok(Self {
-            polkadot_collator_address: polkadot_address,
-            kusama_collator_address: kusama_address,
-            proxy_seed,
-            slack_webhook_url: slack_webhook,
-            slack_bot_token,
-            slack_channel,
-            slack_user_ids_onchain,
-            slack_user_ids_ops,
-            check_interval_secs: check_interval,
-            summary_interval_secs: summary_interval,
-            chains,
-        })
+        let mut builder = AppConfig::builder()
+            .polkadot_collator_address(polkadot_address)
+            .kusama_collator_address(kusama_address)
+            .keystore_passphrase(keystore_passphrase)
+            .proxy_key_name(proxy_key_name)
+            .keystore_dir(keystore_dir)
+            .reward_ledger_path(reward_ledger_path)
+            .offline_payload_dir(offline_payload_dir)
+            .slack_user_ids_onchain(slack_user_ids_onchain)
+            .slack_user_ids_ops(slack_user_ids_ops)
+            .tip(tip)
+            .tip_ceiling(tip_ceiling)
+            .resubmit_after_blocks(resubmit_after_blocks)
+            .bridge_lane_backlog_threshold(bridge_lane_backlog_threshold)
+            .bridge_relay_stall_cycles(bridge_relay_stall_cycles)
+            .check_interval_secs(check_interval)
+            .summary_interval_secs(summary_interval)
+            .chains(chains);
+
+        if let Some(addr) = westend_address {
+            builder = builder.westend_collator_address(addr);
+        }
+        if let Some(addr) = paseo_address {
+            builder = builder.paseo_collator_address(addr);
+        }
+        if let Some(seed) = proxy_seed {
+            builder = builder.proxy_seed(seed);
+        }
+        if let Some(url) = remote_signer_url {
+            builder = builder.remote_signer_url(url);
+        }
+        if let Some(id) = proxy_account_id {
+            builder = builder.proxy_account_id(id);
+        }
+        if let Some(url) = slack_webhook {
+            builder = builder.slack_webhook_url(url);
+        }
+        if let Some(token) = slack_bot_token {
+            builder = builder.slack_bot_token(token);
+        }
+        if let Some(channel) = slack_channel {
+            builder = builder.slack_channel(channel);
+        }
+        if let Some(url) = discord_webhook_url {
+            builder = builder.discord_webhook_url(url);
+        }
+        if let Some(token) = telegram_bot_token {
+            builder = builder.telegram_bot_token(token);
+        }
+        if let Some(chat_id) = telegram_chat_id {
+            builder = builder.telegram_chat_id(chat_id);
+        }
+        if let Some(url) = webhook_url {
+            builder = builder.webhook_url(url);
+        }
+        if let Some(secret) = webhook_signing_secret {
+            builder = builder.webhook_signing_secret(secret);
+        }
+        if let Some(key) = pagerduty_routing_key {
+            builder = builder.pagerduty_routing_key(key);
+        }
+        if let Some(addr) = metrics_bind_addr {
+            builder = builder.metrics_bind_addr(addr);
+        }
+        if let Some(blocks) = polkadot_session_length_blocks {
+            builder = builder.polkadot_session_length_blocks(blocks);
+        }
+        if let Some(blocks) = kusama_session_length_blocks {
+            builder = builder.kusama_session_length_blocks(blocks);
+        }
+        if let Some(blocks) = westend_session_length_blocks {
+            builder = builder.westend_session_length_blocks(blocks);
+        }
+        if let Some(blocks) = paseo_session_length_blocks {
+            builder = builder.paseo_session_length_blocks(blocks);
+        }
+        if let Some(key) = light_client_chain_key {
+            builder = builder.light_client_chain_key(key);
+        }
+        if let Some(path) = light_client_relay_spec_path {
+            builder = builder.light_client_relay_spec_path(path);
+        }
+        if let Some(path) = light_client_chain_spec_path {
+            builder = builder.light_client_chain_spec_path(path);
+        }
+
+        Ok(builder.build()?)
     }
 
     /// Load chain-specific configs from config.toml
@@ -306,6 +702,364 @@ impl AppConfig {
     }
 }
 
+/// Programmatic builder for [`AppConfig`], for embedding this crate as a
+/// library (tests, bespoke dashboards, multi-tenant runners) instead of going
+/// through [`AppConfig::from_env`]'s env-var/`.env`/`config.toml` stack.
+/// Setters consume and return `self` so calls chain; [`Self::build`] fills in
+/// the same defaults `from_env` does for anything left unset, and validates
+/// the handful of fields with no sane default, returning
+/// `CollatorError::ConfigError` instead of panicking.
+#[derive(Debug, Default)]
+pub struct AppConfigBuilder {
+    polkadot_collator_address: Option<String>,
+    kusama_collator_address: Option<String>,
+    westend_collator_address: Option<String>,
+    paseo_collator_address: Option<String>,
+    proxy_seed: Option<String>,
+    proxy_key_name: Option<String>,
+    keystore_dir: Option<String>,
+    keystore_passphrase: Option<String>,
+    remote_signer_url: Option<String>,
+    proxy_account_id: Option<String>,
+    reward_ledger_path: Option<String>,
+    offline_payload_dir: Option<String>,
+    slack_webhook_url: Option<String>,
+    slack_bot_token: Option<String>,
+    slack_channel: Option<String>,
+    discord_webhook_url: Option<String>,
+    telegram_bot_token: Option<String>,
+    telegram_chat_id: Option<String>,
+    webhook_url: Option<String>,
+    webhook_signing_secret: Option<String>,
+    pagerduty_routing_key: Option<String>,
+    metrics_bind_addr: Option<String>,
+    slack_user_ids_onchain: Vec<String>,
+    slack_user_ids_ops: Vec<String>,
+    tip: Option<u128>,
+    tip_ceiling: Option<u128>,
+    resubmit_after_blocks: Option<u64>,
+    polkadot_session_length_blocks: Option<u64>,
+    kusama_session_length_blocks: Option<u64>,
+    westend_session_length_blocks: Option<u64>,
+    paseo_session_length_blocks: Option<u64>,
+    bridge_lane_backlog_threshold: Option<u64>,
+    bridge_relay_stall_cycles: Option<u32>,
+    check_interval_secs: Option<u64>,
+    summary_interval_secs: Option<u64>,
+    chains: HashMap<String, ChainConfig>,
+    light_client_chain_key: Option<String>,
+    light_client_relay_spec_path: Option<String>,
+    light_client_chain_spec_path: Option<String>,
+}
+
+impl AppConfigBuilder {
+    /// Collator account address for Polkadot chains (SS58 format) - required.
+    pub fn polkadot_collator_address(mut self, address: impl Into<String>) -> Self {
+        self.polkadot_collator_address = Some(address.into());
+        self
+    }
+
+    /// Collator account address for Kusama chains (SS58 format) - required.
+    pub fn kusama_collator_address(mut self, address: impl Into<String>) -> Self {
+        self.kusama_collator_address = Some(address.into());
+        self
+    }
+
+    /// Collator account address for Westend chains - see `AppConfig::westend_collator_address`.
+    pub fn westend_collator_address(mut self, address: impl Into<String>) -> Self {
+        self.westend_collator_address = Some(address.into());
+        self
+    }
+
+    /// Collator account address for Paseo chains - see `AppConfig::paseo_collator_address`.
+    pub fn paseo_collator_address(mut self, address: impl Into<String>) -> Self {
+        self.paseo_collator_address = Some(address.into());
+        self
+    }
+
+    /// Legacy plaintext proxy seed, imported into the keystore on first startup.
+    pub fn proxy_seed(mut self, seed: impl Into<String>) -> Self {
+        self.proxy_seed = Some(seed.into());
+        self
+    }
+
+    /// Name of the proxy signing key to look up in the keystore. Defaults to `"proxy"`.
+    pub fn proxy_key_name(mut self, name: impl Into<String>) -> Self {
+        self.proxy_key_name = Some(name.into());
+        self
+    }
+
+    /// Directory holding encrypted keystore files. Defaults to `"keys"`.
+    pub fn keystore_dir(mut self, dir: impl Into<String>) -> Self {
+        self.keystore_dir = Some(dir.into());
+        self
+    }
+
+    /// Passphrase used to derive the on-disk keystore's encryption key - required.
+    pub fn keystore_passphrase(mut self, passphrase: impl Into<String>) -> Self {
+        self.keystore_passphrase = Some(passphrase.into());
+        self
+    }
+
+    /// URL of an external HTTP/JSON signing endpoint - see `AppConfig::remote_signer_url`.
+    pub fn remote_signer_url(mut self, url: impl Into<String>) -> Self {
+        self.remote_signer_url = Some(url.into());
+        self
+    }
+
+    /// SS58 address of the proxy account, required alongside `remote_signer_url`.
+    pub fn proxy_account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.proxy_account_id = Some(account_id.into());
+        self
+    }
+
+    /// Path to the reward ledger JSON file. Defaults to `"reward_ledger.json"`.
+    pub fn reward_ledger_path(mut self, path: impl Into<String>) -> Self {
+        self.reward_ledger_path = Some(path.into());
+        self
+    }
+
+    /// Directory `--sign-only` writes offline-signing payloads into. Defaults to `"offline-payloads"`.
+    pub fn offline_payload_dir(mut self, dir: impl Into<String>) -> Self {
+        self.offline_payload_dir = Some(dir.into());
+        self
+    }
+
+    /// Slack webhook URL for notifications.
+    pub fn slack_webhook_url(mut self, url: impl Into<String>) -> Self {
+        self.slack_webhook_url = Some(url.into());
+        self
+    }
+
+    /// Slack bot token for full API access (update/delete messages).
+    pub fn slack_bot_token(mut self, token: impl Into<String>) -> Self {
+        self.slack_bot_token = Some(token.into());
+        self
+    }
+
+    /// Slack channel ID or name - required when `slack_bot_token` is set.
+    pub fn slack_channel(mut self, channel: impl Into<String>) -> Self {
+        self.slack_channel = Some(channel.into());
+        self
+    }
+
+    /// Discord incoming webhook URL.
+    pub fn discord_webhook_url(mut self, url: impl Into<String>) -> Self {
+        self.discord_webhook_url = Some(url.into());
+        self
+    }
+
+    /// Telegram bot token - see `AppConfig::telegram_bot_token`.
+    pub fn telegram_bot_token(mut self, token: impl Into<String>) -> Self {
+        self.telegram_bot_token = Some(token.into());
+        self
+    }
+
+    /// Telegram chat ID - required alongside `telegram_bot_token`.
+    pub fn telegram_chat_id(mut self, chat_id: impl Into<String>) -> Self {
+        self.telegram_chat_id = Some(chat_id.into());
+        self
+    }
+
+    /// Generic webhook URL for alert delivery.
+    pub fn webhook_url(mut self, url: impl Into<String>) -> Self {
+        self.webhook_url = Some(url.into());
+        self
+    }
+
+    /// HMAC-SHA256 signing secret for `webhook_url`.
+    pub fn webhook_signing_secret(mut self, secret: impl Into<String>) -> Self {
+        self.webhook_signing_secret = Some(secret.into());
+        self
+    }
+
+    /// PagerDuty Events API v2 routing key.
+    pub fn pagerduty_routing_key(mut self, key: impl Into<String>) -> Self {
+        self.pagerduty_routing_key = Some(key.into());
+        self
+    }
+
+    /// Bind address for the Prometheus scrape endpoint.
+    pub fn metrics_bind_addr(mut self, addr: impl Into<String>) -> Self {
+        self.metrics_bind_addr = Some(addr.into());
+        self
+    }
+
+    /// Slack user IDs to ping for on-chain actions.
+    pub fn slack_user_ids_onchain(mut self, ids: Vec<String>) -> Self {
+        self.slack_user_ids_onchain = ids;
+        self
+    }
+
+    /// Slack user IDs to ping for ops issues.
+    pub fn slack_user_ids_ops(mut self, ids: Vec<String>) -> Self {
+        self.slack_user_ids_ops = ids;
+        self
+    }
+
+    /// Transaction tip attached to registration/bond-update extrinsics. Defaults to 0.
+    pub fn tip(mut self, tip: u128) -> Self {
+        self.tip = Some(tip);
+        self
+    }
+
+    /// Ceiling a resubmission may escalate `tip` to. Defaults to 0 (resubmission disabled).
+    pub fn tip_ceiling(mut self, tip_ceiling: u128) -> Self {
+        self.tip_ceiling = Some(tip_ceiling);
+        self
+    }
+
+    /// Finalized blocks an unincluded extrinsic may sit for before resubmission. Defaults to 0 (disabled).
+    pub fn resubmit_after_blocks(mut self, blocks: u64) -> Self {
+        self.resubmit_after_blocks = Some(blocks);
+        self
+    }
+
+    /// Estimated Polkadot session length in blocks - see `AppConfig::polkadot_session_length_blocks`.
+    pub fn polkadot_session_length_blocks(mut self, blocks: u64) -> Self {
+        self.polkadot_session_length_blocks = Some(blocks);
+        self
+    }
+
+    /// Estimated Kusama session length in blocks.
+    pub fn kusama_session_length_blocks(mut self, blocks: u64) -> Self {
+        self.kusama_session_length_blocks = Some(blocks);
+        self
+    }
+
+    /// Estimated Westend session length in blocks.
+    pub fn westend_session_length_blocks(mut self, blocks: u64) -> Self {
+        self.westend_session_length_blocks = Some(blocks);
+        self
+    }
+
+    /// Estimated Paseo session length in blocks.
+    pub fn paseo_session_length_blocks(mut self, blocks: u64) -> Self {
+        self.paseo_session_length_blocks = Some(blocks);
+        self
+    }
+
+    /// Outbound bridge lane backlog threshold - see `AppConfig::bridge_lane_backlog_threshold`. Defaults to 50.
+    pub fn bridge_lane_backlog_threshold(mut self, threshold: u64) -> Self {
+        self.bridge_lane_backlog_threshold = Some(threshold);
+        self
+    }
+
+    /// Consecutive stalled cycles before a bridge finality relay is flagged. Defaults to 3.
+    pub fn bridge_relay_stall_cycles(mut self, cycles: u32) -> Self {
+        self.bridge_relay_stall_cycles = Some(cycles);
+        self
+    }
+
+    /// Check interval in seconds for continuous monitoring mode. Defaults to 3600 (1 hour).
+    pub fn check_interval_secs(mut self, secs: u64) -> Self {
+        self.check_interval_secs = Some(secs);
+        self
+    }
+
+    /// Summary interval in seconds for the periodic status summary. Defaults to 21600 (6 hours).
+    pub fn summary_interval_secs(mut self, secs: u64) -> Self {
+        self.summary_interval_secs = Some(secs);
+        self
+    }
+
+    /// Set the RPC config for a single `(network, chain)` pair.
+    pub fn chain(mut self, network: Network, chain: SystemChain, config: ChainConfig) -> Self {
+        self.chains.insert(chain_key(network, chain), config);
+        self
+    }
+
+    /// Replace the entire per-chain config map in one call.
+    pub fn chains(mut self, chains: HashMap<String, ChainConfig>) -> Self {
+        self.chains = chains;
+        self
+    }
+
+    /// `(network, chain)` this monitor should connect to via the embedded
+    /// light client, in "network_chain" key format - see
+    /// `AppConfig::light_client_chain_key`.
+    pub fn light_client_chain_key(mut self, key: impl Into<String>) -> Self {
+        self.light_client_chain_key = Some(key.into());
+        self
+    }
+
+    /// Path to the relay chain's chain spec JSON - see
+    /// `AppConfig::light_client_relay_spec_path`.
+    pub fn light_client_relay_spec_path(mut self, path: impl Into<String>) -> Self {
+        self.light_client_relay_spec_path = Some(path.into());
+        self
+    }
+
+    /// Path to the parachain's chain spec JSON - see
+    /// `AppConfig::light_client_chain_spec_path`.
+    pub fn light_client_chain_spec_path(mut self, path: impl Into<String>) -> Self {
+        self.light_client_chain_spec_path = Some(path.into());
+        self
+    }
+
+    /// Validate and assemble the final `AppConfig`, filling in defaults for
+    /// anything left unset. Fails with `CollatorError::ConfigError` if a
+    /// required field (`polkadot_collator_address`, `kusama_collator_address`,
+    /// or `keystore_passphrase`) is missing, rather than panicking.
+    pub fn build(self) -> Result<AppConfig, CollatorError> {
+        let polkadot_collator_address = self
+            .polkadot_collator_address
+            .ok_or_else(|| CollatorError::ConfigError("polkadot_collator_address is required".to_string()))?;
+        let kusama_collator_address = self
+            .kusama_collator_address
+            .ok_or_else(|| CollatorError::ConfigError("kusama_collator_address is required".to_string()))?;
+        let keystore_passphrase = self
+            .keystore_passphrase
+            .ok_or_else(|| CollatorError::ConfigError("keystore_passphrase is required".to_string()))?;
+
+        Ok(AppConfig {
+            polkadot_collator_address,
+            kusama_collator_address,
+            westend_collator_address: self.westend_collator_address,
+            paseo_collator_address: self.paseo_collator_address,
+            proxy_seed: self.proxy_seed,
+            proxy_key_name: self.proxy_key_name.unwrap_or_else(default_proxy_key_name),
+            keystore_dir: self.keystore_dir.unwrap_or_else(default_keystore_dir),
+            keystore_passphrase,
+            remote_signer_url: self.remote_signer_url,
+            proxy_account_id: self.proxy_account_id,
+            reward_ledger_path: self.reward_ledger_path.unwrap_or_else(default_reward_ledger_path),
+            offline_payload_dir: self.offline_payload_dir.unwrap_or_else(default_offline_payload_dir),
+            slack_webhook_url: self.slack_webhook_url,
+            slack_bot_token: self.slack_bot_token,
+            slack_channel: self.slack_channel,
+            discord_webhook_url: self.discord_webhook_url,
+            telegram_bot_token: self.telegram_bot_token,
+            telegram_chat_id: self.telegram_chat_id,
+            webhook_url: self.webhook_url,
+            webhook_signing_secret: self.webhook_signing_secret,
+            pagerduty_routing_key: self.pagerduty_routing_key,
+            metrics_bind_addr: self.metrics_bind_addr,
+            slack_user_ids_onchain: self.slack_user_ids_onchain,
+            slack_user_ids_ops: self.slack_user_ids_ops,
+            tip: self.tip.unwrap_or(0),
+            tip_ceiling: self.tip_ceiling.unwrap_or(0),
+            resubmit_after_blocks: self.resubmit_after_blocks.unwrap_or(0),
+            polkadot_session_length_blocks: self.polkadot_session_length_blocks,
+            kusama_session_length_blocks: self.kusama_session_length_blocks,
+            westend_session_length_blocks: self.westend_session_length_blocks,
+            paseo_session_length_blocks: self.paseo_session_length_blocks,
+            bridge_lane_backlog_threshold: self
+                .bridge_lane_backlog_threshold
+                .unwrap_or_else(default_bridge_lane_backlog_threshold),
+            bridge_relay_stall_cycles: self
+                .bridge_relay_stall_cycles
+                .unwrap_or_else(default_bridge_relay_stall_cycles),
+            check_interval_secs: self.check_interval_secs.unwrap_or_else(default_check_interval),
+            summary_interval_secs: self.summary_interval_secs.unwrap_or_else(default_summary_interval),
+            chains: self.chains,
+            light_client_chain_key: self.light_client_chain_key,
+            light_client_relay_spec_path: self.light_client_relay_spec_path,
+            light_client_chain_spec_path: self.light_client_chain_spec_path,
+        })
+    }
+}
+
 /// Default RPC endpoints for system chains
 /// Returns array of URLs: [LuckyFriday (primary), Stakeworld (fallback), Dotters (fallback)]
 pub fn default_rpc_urls(network: Network, chain: SystemChain) -> Vec<&'static str> {
@@ -364,9 +1118,75 @@ pub fn default_rpc_urls(network: Network, chain: SystemChain) -> Vec<&'static st
             "wss://encointer-kusama-rpc.dotters.network",
         ],
 
-        // Invalid combinations
-        (Network::Polkadot, SystemChain::Encointer) => panic!("Encointer is only on Kusama"),
-        (Network::Kusama, SystemChain::Collectives) => panic!("Collectives is only on Polkadot"),
+        // Westend system chains
+        (Network::Westend, SystemChain::AssetHub) => vec![
+            "wss://westend-asset-hub-rpc.polkadot.io",
+            "wss://asset-hub-westend-rpc.dwellir.com",
+        ],
+        (Network::Westend, SystemChain::BridgeHub) => vec![
+            "wss://westend-bridge-hub-rpc.polkadot.io",
+            "wss://bridge-hub-westend-rpc.dwellir.com",
+        ],
+        (Network::Westend, SystemChain::Collectives) => vec![
+            "wss://westend-collectives-rpc.polkadot.io",
+            "wss://collectives-westend-rpc.dwellir.com",
+        ],
+        (Network::Westend, SystemChain::Coretime) => vec![
+            "wss://westend-coretime-rpc.polkadot.io",
+            "wss://coretime-westend-rpc.dwellir.com",
+        ],
+        (Network::Westend, SystemChain::People) => vec![
+            "wss://westend-people-rpc.polkadot.io",
+            "wss://people-westend-rpc.dwellir.com",
+        ],
+        (Network::Westend, SystemChain::Glutton) => vec![
+            "wss://westend-glutton-rpc.polkadot.io",
+            "wss://glutton-westend-rpc.dwellir.com",
+        ],
+
+        // Paseo system chains
+        (Network::Paseo, SystemChain::AssetHub) => vec![
+            "wss://asset-hub-paseo-rpc.dwellir.com",
+            "wss://sys.ibp.network/asset-hub-paseo",
+        ],
+        (Network::Paseo, SystemChain::BridgeHub) => vec![
+            "wss://bridge-hub-paseo-rpc.dwellir.com",
+            "wss://sys.ibp.network/bridge-hub-paseo",
+        ],
+        (Network::Paseo, SystemChain::Coretime) => vec![
+            "wss://coretime-paseo-rpc.dwellir.com",
+            "wss://sys.ibp.network/coretime-paseo",
+        ],
+        (Network::Paseo, SystemChain::People) => vec![
+            "wss://people-paseo-rpc.dwellir.com",
+            "wss://sys.ibp.network/people-paseo",
+        ],
+
+        // Rococo system chains
+        (Network::Rococo, SystemChain::AssetHub) => vec![
+            "wss://rococo-asset-hub-rpc.polkadot.io",
+            "wss://asset-hub-rococo-rpc.dwellir.com",
+        ],
+        (Network::Rococo, SystemChain::BridgeHub) => vec![
+            "wss://rococo-bridge-hub-rpc.polkadot.io",
+            "wss://bridge-hub-rococo-rpc.dwellir.com",
+        ],
+        (Network::Rococo, SystemChain::Collectives) => vec![
+            "wss://rococo-collectives-rpc.polkadot.io",
+        ],
+        (Network::Rococo, SystemChain::Coretime) => vec![
+            "wss://rococo-coretime-rpc.polkadot.io",
+        ],
+        (Network::Rococo, SystemChain::People) => vec![
+            "wss://rococo-people-rpc.polkadot.io",
+        ],
+        (Network::Rococo, SystemChain::Glutton) => vec![
+            "wss://rococo-glutton-rpc.polkadot.io",
+        ],
+
+        // Invalid combinations - `chain.valid_networks()` is the source of
+        // truth; anything not listed above isn't a real deployment.
+        (network, chain) => panic!("{:?} is not deployed on {:?}", chain, network),
     }
 }
 
@@ -381,6 +1201,8 @@ pub fn chain_supports_proxy(chain: SystemChain) -> bool {
     match chain {
         // BridgeHub doesn't support proxy accounts for collator registration
         SystemChain::BridgeHub => false,
+        // Glutton is a stress-test chain with no Balances/Proxy pallets
+        SystemChain::Glutton => false,
         // All other chains support proxy
         _ => true,
     }
@@ -2,6 +2,20 @@
 
 use thiserror::Error;
 
+/// How a caller should react to a given error, so registration/bond flows can be
+/// handled precisely instead of collapsing everything into an opaque string: an
+/// RPC hiccup is worth retrying on the next check, a missing proxy authorization
+/// needs a human, and everything else is treated as a hard failure for that cycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorCategory {
+    /// Transient - safe to retry the same operation on the next check cycle.
+    Retryable,
+    /// Won't resolve itself; a human needs to act (authorize the proxy, add funds).
+    ManualAction,
+    /// Not expected to succeed on retry and doesn't need a human either - just surface it.
+    Fatal,
+}
+
 #[derive(Error, Debug)]
 pub enum CollatorError {
     #[error("Failed to connect to chain: {0}")]
@@ -30,4 +44,87 @@ pub enum CollatorError {
 
     #[error("Chain {chain} is not available on {network}")]
     ChainNotAvailable { chain: String, network: String },
+
+    #[error("Registration failed: {0}")]
+    RegistrationFailed(String),
+
+    #[error("Bond update failed: {0}")]
+    BondUpdateFailed(String),
+
+    #[error("Insufficient balance: have {have}, need {need}")]
+    InsufficientBalance { have: u128, need: u128 },
+
+    #[error("Proxy is not authorized for this call: {0}")]
+    ProxyNotAuthorized(String),
+
+    #[error("Failed to parse signing key material: {0}")]
+    KeyParse(String),
+
+    #[error(
+        "offline payload's mortality window has expired: current block {current_block}, \
+        expired at block {expires_at_block} - re-prepare and re-sign the payload"
+    )]
+    MortalityExpired { current_block: u64, expires_at_block: u64 },
+
+    #[error("RPC error: {0}")]
+    Rpc(#[from] subxt::Error),
+
+    #[error(
+        "live metadata on {chain} ({network}) has drifted from the metadata this binary was \
+        compiled against - falling back to read-only until it's redeployed with fresh metadata"
+    )]
+    MetadataOutOfDate { chain: String, network: String },
+
+    #[error("monitor is shutting down - refusing to start a new transaction")]
+    Shutdown,
+}
+
+impl CollatorError {
+    /// How the caller should react to this error.
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            CollatorError::Rpc(_)
+            | CollatorError::ConnectionFailed(_)
+            | CollatorError::StorageQueryFailed(_)
+            | CollatorError::TransactionFailed(_)
+            | CollatorError::RegistrationFailed(_)
+            | CollatorError::BondUpdateFailed(_)
+            | CollatorError::SlackNotificationFailed(_) => ErrorCategory::Retryable,
+
+            CollatorError::ProxyNotAuthorized(_)
+            | CollatorError::InsufficientFunds { .. }
+            | CollatorError::InsufficientBalance { .. }
+            | CollatorError::MortalityExpired { .. }
+            | CollatorError::MetadataOutOfDate { .. } => ErrorCategory::ManualAction,
+
+            CollatorError::AccountNotFound(_)
+            | CollatorError::InvalidAddress(_)
+            | CollatorError::KeyParse(_)
+            | CollatorError::ConfigError(_)
+            | CollatorError::ChainNotAvailable { .. }
+            | CollatorError::Shutdown => ErrorCategory::Fatal,
+        }
+    }
+
+    /// Safe to retry the same operation on the next check cycle.
+    pub fn is_retryable(&self) -> bool {
+        self.category() == ErrorCategory::Retryable
+    }
+
+    /// Won't resolve itself - a human needs to act.
+    pub fn requires_manual_action(&self) -> bool {
+        self.category() == ErrorCategory::ManualAction
+    }
+}
+
+impl From<bip39::Error> for CollatorError {
+    fn from(e: bip39::Error) -> Self {
+        CollatorError::KeyParse(e.to_string())
+    }
+}
+
+impl From<hex::FromHexError> for CollatorError {
+    fn from(e: hex::FromHexError) -> Self {
+        CollatorError::KeyParse(e.to_string())
+    }
 }
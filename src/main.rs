@@ -4,22 +4,35 @@
 //! and automatically re-registers as a candidate if the collator falls out of the
 //! candidate list or invulnerables list.
 
+mod account_lookup;
+mod authorship_tracker;
 mod block_tracker;
 mod chain_client;
+mod collator_events;
 mod config;
 mod error;
+mod keystore;
 mod metadata;
+mod metadata_drift;
+mod metrics;
 mod monitor;
+mod notification_backend;
+mod scale_path;
+mod shutdown;
+mod signer;
 mod slack;
+mod ss58;
 
 use std::sync::Arc;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
-use tracing::{error, info};
+use tracing::{debug, error, info, warn};
 use tracing_subscriber::EnvFilter;
 
 use crate::block_tracker::BlockTracker;
+use crate::collator_events::CollatorEvent;
 use crate::config::AppConfig;
+use crate::keystore::{import_from_str, EncryptedFileKeystore};
 use crate::monitor::{CollatorMonitor, MonitorStatus};
 use crate::slack::SlackNotifier;
 
@@ -34,6 +47,34 @@ struct Cli {
     /// Log level (trace, debug, info, warn, error)
     #[arg(long, default_value = "info", global = true)]
     log_level: String,
+
+    /// Build and sign registration/bond-update extrinsics without broadcasting
+    /// them - prints the signed payload for offline review or submission elsewhere
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    /// On the registration path, write an offline-signing payload instead of
+    /// signing locally at all - for proxy keys that live on an air-gapped
+    /// machine. See the `prepare`/`broadcast` subcommands.
+    #[arg(long, global = true)]
+    sign_only: bool,
+
+    /// Output format for `check`/`watch`/`status` results - `text` for the
+    /// human-readable default, `json` for dashboards, cron wrappers, and
+    /// alerting pipelines to consume instead of parsing log lines.
+    #[arg(long, global = true, default_value = "text")]
+    output: OutputFormat,
+
+    /// Override the configured transaction tip (in the chain's smallest unit)
+    /// for registration/bond-update extrinsics - see `AppConfig::tip`.
+    #[arg(long, global = true)]
+    tip: Option<u128>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
 }
 
 #[derive(Subcommand)]
@@ -53,6 +94,97 @@ enum Commands {
 
     /// Show configuration (for debugging)
     ShowConfig,
+
+    /// Import a signing key (mnemonic, hex seed, or secret URI) into the
+    /// encrypted keystore under a name
+    ImportKey {
+        /// Name to store this key under (e.g. "proxy")
+        name: String,
+
+        /// Secret material: a BIP39 mnemonic, a 0x-prefixed hex seed, or a
+        /// secret URI such as "//Alice"
+        seed: String,
+    },
+
+    /// Fetch everything needed to build a registration/bond-update extrinsic and
+    /// write it to a portable payload file for offline signing
+    Prepare {
+        /// Network to prepare the transaction for ("polkadot", "kusama", "westend" or "paseo")
+        #[arg(long)]
+        network: String,
+
+        /// System chain to prepare the transaction for (e.g. "assethub")
+        #[arg(long)]
+        chain: String,
+
+        /// New bond amount to set; omit to prepare a registration instead
+        #[arg(long)]
+        bond: Option<u128>,
+
+        /// SS58 address of the proxy account that will sign the payload offline
+        #[arg(long)]
+        proxy_account: String,
+
+        /// Where to write the payload file
+        #[arg(long, default_value = "offline-payload.json")]
+        output: std::path::PathBuf,
+    },
+
+    /// Apply an externally-produced signature from a `prepare`d payload file
+    /// and broadcast the resulting extrinsic
+    Broadcast {
+        /// Network the payload was prepared for ("polkadot", "kusama", "westend" or "paseo")
+        #[arg(long)]
+        network: String,
+
+        /// System chain the payload was prepared for (e.g. "assethub")
+        #[arg(long)]
+        chain: String,
+
+        /// Path to the signed payload file
+        #[arg(long, default_value = "offline-payload.json")]
+        input: std::path::PathBuf,
+    },
+
+    /// Look up an Aura session key in a chain's current authority set by a
+    /// leading hex prefix, for when only a truncated key was logged
+    FindAuthority {
+        /// Network to query ("polkadot", "kusama", "westend" or "paseo")
+        #[arg(long)]
+        network: String,
+
+        /// System chain to query (e.g. "assethub")
+        #[arg(long)]
+        chain: String,
+
+        /// Leading hex prefix to match, optionally "0x"-prefixed (e.g. "0xdeadbeef")
+        prefix: String,
+    },
+
+    /// Fetch and decode one storage item the grabber knows how to parse, for
+    /// ad hoc inspection without a dedicated command
+    DumpStorage {
+        /// Network to query ("polkadot", "kusama", "westend" or "paseo")
+        #[arg(long)]
+        network: String,
+
+        /// System chain to query (e.g. "assethub")
+        #[arg(long)]
+        chain: String,
+
+        /// Storage pallet name (e.g. "Aura", "System")
+        #[arg(long)]
+        pallet: String,
+
+        /// Storage item name within the pallet (e.g. "Authorities", "Account")
+        #[arg(long)]
+        item: String,
+
+        /// SS58 address to use as the storage key, for account-keyed items
+        /// such as "System::Account"
+        #[arg(long)]
+        account: Option<String>,
+    },
 }
 
 #[tokio::main]
@@ -71,36 +203,63 @@ async fn main() -> Result<()> {
     info!("Collator Monitor starting up");
 
     // Load configuration
-    let config = AppConfig::load()?;
+    let mut config = AppConfig::load()?;
+    if let Some(tip) = cli.tip {
+        config.tip = tip;
+    }
 
     match cli.command {
         Commands::Check => {
-            run_check(config).await?;
+            run_check(config, cli.dry_run, cli.sign_only, cli.output).await?;
         }
         Commands::Watch { interval } => {
             let interval_secs = interval.unwrap_or(config.check_interval_secs);
-            run_watch(config, interval_secs).await?;
+            run_watch(config, interval_secs, cli.dry_run, cli.sign_only, cli.output).await?;
         }
         Commands::Status => {
-            run_status(config).await?;
+            run_status(config, cli.output).await?;
         }
         Commands::ShowConfig => {
             println!("{:#?}", config);
         }
+        Commands::ImportKey { name, seed } => {
+            run_import_key(&config, &name, &seed)?;
+        }
+        Commands::Prepare { network, chain, bond, proxy_account, output } => {
+            run_prepare(config, &network, &chain, bond, &proxy_account, &output).await?;
+        }
+        Commands::Broadcast { network, chain, input } => {
+            run_broadcast(config, &network, &chain, &input).await?;
+        }
+        Commands::FindAuthority { network, chain, prefix } => {
+            run_find_authority(config, &network, &chain, &prefix).await?;
+        }
+        Commands::DumpStorage { network, chain, pallet, item, account } => {
+            run_dump_storage(config, &network, &chain, &pallet, &item, account.as_deref()).await?;
+        }
     }
 
     Ok(())
 }
 
-async fn run_check(config: AppConfig) -> Result<()> {
+async fn run_check(config: AppConfig, dry_run: bool, sign_only: bool, output: OutputFormat) -> Result<()> {
     info!("Running single check across all chains");
+    if dry_run {
+        info!("Dry run enabled: registrations will be signed but not broadcast");
+    }
+    if sign_only {
+        info!("Sign-only enabled: registrations will write an offline payload instead of signing locally");
+    }
 
     // For single check, we don't use background block tracker
     let block_tracker = Arc::new(BlockTracker::new());
-    let monitor = CollatorMonitor::new(config, block_tracker)?;
+    block_tracker
+        .load_reward_ledger(std::path::PathBuf::from(&config.reward_ledger_path))
+        .await;
+    let monitor = CollatorMonitor::new(config, block_tracker, dry_run, sign_only)?;
     let results = monitor.monitor_all_chains().await;
 
-    print_results(&results);
+    print_results(&results, output);
 
     // Check if any errors occurred
     let has_errors = results
@@ -115,53 +274,135 @@ async fn run_check(config: AppConfig) -> Result<()> {
     Ok(())
 }
 
-async fn run_watch(config: AppConfig, interval_secs: u64) -> Result<()> {
+async fn run_watch(
+    config: AppConfig,
+    interval_secs: u64,
+    dry_run: bool,
+    sign_only: bool,
+    output: OutputFormat,
+) -> Result<()> {
     let summary_interval_secs = config.summary_interval_secs;
-    
+
     info!(
         "Starting continuous monitoring with {} second interval, summary every {} seconds",
         interval_secs, summary_interval_secs
     );
+    if dry_run {
+        info!("Dry run enabled: registrations will be signed but not broadcast");
+    }
+    if sign_only {
+        info!("Sign-only enabled: registrations will write an offline payload instead of signing locally");
+    }
 
-    // Create slack notifier - prefer bot token for full functionality
-    let slack = Arc::new(
-        if let (Some(bot_token), Some(channel)) = (&config.slack_bot_token, &config.slack_channel) {
-            info!("Using Slack bot token (message update/delete enabled)");
-            SlackNotifier::with_bot_token(
-                bot_token.clone(),
-                channel.clone(),
-                config.slack_user_ids_onchain.clone(),
-                config.slack_user_ids_ops.clone(),
-            )
-        } else {
-            info!("Using Slack webhook (message update/delete disabled)");
-            SlackNotifier::new(
-                config.slack_webhook_url.clone(),
-                config.slack_user_ids_onchain.clone(),
-                config.slack_user_ids_ops.clone(),
-            )
-        }
-    );
+    // Create notifier - prefers Slack bot token for full functionality, then
+    // fans out to whichever other channels (Discord/Telegram/webhook/PagerDuty)
+    // are configured.
+    if config.slack_bot_token.is_some() && config.slack_channel.is_some() {
+        info!("Using Slack bot token (message update/delete enabled)");
+    } else {
+        info!("Using Slack webhook (message update/delete disabled)");
+    }
+    let slack = Arc::new(SlackNotifier::from_config(&config));
 
     // Start background block trackers with slack integration
     let block_tracker = Arc::new(BlockTracker::new());
-    let _tracker_handles = block_tracker.clone().start_tracking(config.clone(), slack.clone());
-    
+    block_tracker
+        .load_reward_ledger(std::path::PathBuf::from(&config.reward_ledger_path))
+        .await;
+
+    // Wire up the metrics registry before trackers start so no early block
+    // authorship or RPC failover events are missed.
+    let metrics_registry = match &config.metrics_bind_addr {
+        Some(bind_addr) => {
+            let bind_addr = bind_addr
+                .parse()
+                .with_context(|| format!("invalid metrics_bind_addr '{}'", bind_addr))?;
+            let registry = Arc::new(crate::metrics::MetricsRegistry::new());
+            block_tracker.set_metrics(registry.clone()).await;
+            tokio::spawn({
+                let registry = registry.clone();
+                async move {
+                    if let Err(e) = crate::metrics::serve(registry, bind_addr).await {
+                        error!("Metrics endpoint stopped: {}", e);
+                    }
+                }
+            });
+            Some(registry)
+        }
+        None => None,
+    };
+
+    // Install signal handlers so a SIGINT/SIGTERM stops new registration/bond
+    // transactions from starting, lets an already-submitted one finalize, and
+    // posts a final status message instead of the process just going quiet.
+    // Installed before `start_tracking` so the trackers' own shutdown signal
+    // (see [`BlockTracker::start_tracking`]) rides this same token rather
+    // than installing a second OS signal listener.
+    let mut shutdown_token = crate::shutdown::install();
+
+    let _tracker_handles = block_tracker.clone().start_tracking(config.clone(), slack.clone(), shutdown_token.clone());
+
     // Give trackers a moment to initialize
     tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
 
-    let monitor = CollatorMonitor::new(config, block_tracker.clone())?;
-    
+    let mut monitor = CollatorMonitor::new(config.clone(), block_tracker.clone(), dry_run, sign_only)?;
+
+    if let Some(registry) = metrics_registry {
+        monitor.set_metrics(registry);
+    }
+
+    monitor.set_shutdown_token(shutdown_token.clone());
+
+    // React to a collator-selection event (undercut, re-bonded, etc.) the
+    // moment it lands instead of waiting for the next poll cycle.
+    {
+        let mut events = monitor.collator_event_watcher().subscribe();
+        let slack = slack.clone();
+        tokio::spawn(async move {
+            while let Ok(event) = events.recv().await {
+                match event {
+                    CollatorEvent::CandidateReplaced { chain_name, evicted, new_candidate, deposit } => {
+                        warn!(
+                            "{}: candidate slot changed hands - evicted {}, now held by {} at bond {}",
+                            chain_name,
+                            hex::encode(evicted.0),
+                            hex::encode(new_candidate.0),
+                            deposit
+                        );
+                        let _ = slack
+                            .notify_error(
+                                &chain_name,
+                                &format!(
+                                    "Candidate slot changed hands: evicted {}, now held by {} at bond {}",
+                                    hex::encode(evicted.0),
+                                    hex::encode(new_candidate.0),
+                                    deposit
+                                ),
+                            )
+                            .await;
+                    }
+                    other => debug!("collator event: {:?}", other),
+                }
+            }
+        });
+    }
+
     let mut last_summary = std::time::Instant::now();
     // Send initial summary
     info!("Sending initial status summary");
     let slots = monitor.collect_slot_info().await;
     let _ = monitor.slack().send_status_summary(&slots).await;
 
+    // Collator set membership can only change at a session rotation, so wake
+    // immediately on one instead of waiting out the fixed interval - which
+    // still runs as a safety-net poll in case a rotation notification is
+    // missed (RPC hiccup, restart).
+    let mut session_rotations = block_tracker.subscribe_session_rotations();
+
     loop {
         info!("Running scheduled check");
         let results = monitor.monitor_all_chains().await;
-        print_results(&results);
+        print_results(&results, output);
 
         // Check if it's time to send a summary
         if last_summary.elapsed().as_secs() >= summary_interval_secs {
@@ -171,24 +412,276 @@ async fn run_watch(config: AppConfig, interval_secs: u64) -> Result<()> {
             last_summary = std::time::Instant::now();
         }
 
-        info!("Next check in {} seconds", interval_secs);
-        tokio::time::sleep(tokio::time::Duration::from_secs(interval_secs)).await;
+        if shutdown_token.is_requested() {
+            info!("Shutdown requested, stopping before the next check");
+            break;
+        }
+
+        info!("Next check in at most {} seconds, or immediately on a session rotation", interval_secs);
+        tokio::select! {
+            _ = tokio::time::sleep(tokio::time::Duration::from_secs(interval_secs)) => {}
+            result = session_rotations.recv() => {
+                match result {
+                    Ok(rotation) => info!(
+                        "{}: session rotated to {} at block #{}, running an immediate check",
+                        rotation.chain_name, rotation.session_index, rotation.block_number
+                    ),
+                    Err(e) => info!("Session-rotation watch interrupted ({}), running an immediate check", e),
+                }
+            }
+            _ = shutdown_token.wait() => {
+                info!("Shutdown requested, stopping after the current cycle");
+            }
+        }
     }
-    
-    // Cleanup (unreachable in normal operation, but good practice)
-    #[allow(unreachable_code)]
-    {
-        block_tracker.shutdown().await;
-        Ok(())
+
+    info!("Monitor stopping");
+    block_tracker.shutdown();
+    monitor.authorship_tracker().shutdown();
+    monitor.collator_event_watcher().shutdown();
+    let _ = monitor
+        .slack()
+        .notify_monitor_stopping("Received a shutdown signal (SIGINT/SIGTERM).")
+        .await;
+    Ok(())
+}
+
+fn run_import_key(config: &AppConfig, name: &str, seed: &str) -> Result<()> {
+    let mut keystore = EncryptedFileKeystore::open(&config.keystore_dir, &config.keystore_passphrase)?;
+    import_from_str(&mut keystore, name, seed)?;
+    println!("Imported key '{}' into {}", name, config.keystore_dir);
+    Ok(())
+}
+
+/// `prepare`/`broadcast` only cover the networks the rest of the monitor
+/// actually registers against today - see `run_status`/`monitor_all_chains`.
+fn parse_network(s: &str) -> Result<crate::config::Network> {
+    use crate::config::Network;
+    match s.to_lowercase().as_str() {
+        "polkadot" => Ok(Network::Polkadot),
+        "kusama" => Ok(Network::Kusama),
+        "westend" => Ok(Network::Westend),
+        "paseo" => Ok(Network::Paseo),
+        other => Err(anyhow::anyhow!(
+            "unsupported network '{}' - prepare/broadcast support polkadot, kusama, westend and paseo",
+            other
+        )),
+    }
+}
+
+fn parse_chain(s: &str) -> Result<crate::config::SystemChain> {
+    use crate::config::SystemChain;
+    match s.to_lowercase().replace(['-', '_'], "").as_str() {
+        "assethub" => Ok(SystemChain::AssetHub),
+        "bridgehub" => Ok(SystemChain::BridgeHub),
+        "collectives" => Ok(SystemChain::Collectives),
+        "coretime" => Ok(SystemChain::Coretime),
+        "people" => Ok(SystemChain::People),
+        "encointer" => Ok(SystemChain::Encointer),
+        "glutton" => Ok(SystemChain::Glutton),
+        other => Err(anyhow::anyhow!("unknown system chain '{}'", other)),
+    }
+}
+
+async fn run_prepare(
+    config: AppConfig,
+    network: &str,
+    chain: &str,
+    bond: Option<u128>,
+    proxy_account: &str,
+    output: &std::path::Path,
+) -> Result<()> {
+    use crate::chain_client::ChainClient;
+    use crate::config::default_rpc_url;
+
+    let network = parse_network(network)?;
+    let chain = parse_chain(chain)?;
+
+    if !chain.valid_networks().contains(&network) {
+        anyhow::bail!("{} is not deployed on {:?}", chain.display_name(network), network);
+    }
+
+    let rpc_urls = config.get_rpc_urls(network, chain);
+    let rpc_url = rpc_urls.first().map(|s| s.as_str()).unwrap_or_else(|| default_rpc_url(network, chain));
+
+    let client = ChainClient::connect(rpc_url, network, chain).await?;
+    let collator_address = config
+        .collator_address(network)
+        .ok_or_else(|| anyhow::anyhow!("no collator address configured for {:?}", network))?;
+    let collator_account = client.parse_address(collator_address)?;
+    let proxy_account = client.parse_address(proxy_account)?;
+
+    let payload = match bond {
+        Some(new_bond) => {
+            client
+                .prepare_bond_update_payload(&collator_account, &proxy_account, new_bond)
+                .await?
+        }
+        None => client.prepare_registration_payload(&collator_account, &proxy_account).await?,
+    };
+
+    let file = std::fs::File::create(output).with_context(|| format!("Failed to create {}", output.display()))?;
+    serde_json::to_writer_pretty(file, &payload).context("Failed to serialize offline payload")?;
+
+    println!("Prepared offline payload: {}", output.display());
+    println!("  call: {}", payload.call_description);
+    println!("  sign this on the air-gapped machine: {}", payload.signer_payload_hex);
+    println!(
+        "  mortality window: block {} through {}",
+        payload.mortal_block_number,
+        payload.mortal_block_number + payload.era_period
+    );
+    println!("  once signed, fill in `signature_hex` in the payload file and run `broadcast`");
+
+    Ok(())
+}
+
+async fn run_broadcast(config: AppConfig, network: &str, chain: &str, input: &std::path::Path) -> Result<()> {
+    use crate::chain_client::{ChainClient, OfflinePayload};
+    use crate::config::default_rpc_url;
+
+    let network = parse_network(network)?;
+    let chain = parse_chain(chain)?;
+
+    let rpc_urls = config.get_rpc_urls(network, chain);
+    let rpc_url = rpc_urls.first().map(|s| s.as_str()).unwrap_or_else(|| default_rpc_url(network, chain));
+
+    let client = ChainClient::connect(rpc_url, network, chain).await?;
+
+    let file = std::fs::File::open(input).with_context(|| format!("Failed to open {}", input.display()))?;
+    let payload: OfflinePayload = serde_json::from_reader(file)
+        .with_context(|| format!("Failed to parse offline payload from {}", input.display()))?;
+
+    let tx_hash = client.broadcast_offline_payload(&payload).await?;
+    println!("Submitted offline-signed {} (tx: {})", payload.call_description, tx_hash);
+
+    Ok(())
+}
+
+async fn run_find_authority(config: AppConfig, network: &str, chain: &str, prefix: &str) -> Result<()> {
+    use crate::account_lookup::find_by_prefix;
+    use crate::chain_client::ChainClient;
+    use crate::config::default_rpc_url;
+
+    let network = parse_network(network)?;
+    let chain = parse_chain(chain)?;
+
+    let rpc_urls = config.get_rpc_urls(network, chain);
+    let rpc_url = rpc_urls.first().map(|s| s.as_str()).unwrap_or_else(|| default_rpc_url(network, chain));
+
+    let client = ChainClient::connect(rpc_url, network, chain).await?;
+    let authorities = client.get_aura_authorities().await?;
+    let matches = find_by_prefix(&authorities, prefix);
+
+    if matches.is_empty() {
+        println!("No Aura authority on {} matches prefix '{}'", chain.display_name(network), prefix);
+        return Ok(());
+    }
+
+    println!("{} Aura authority/authorities on {} match prefix '{}':", matches.len(), chain.display_name(network), prefix);
+    for account in matches {
+        println!("  {}", crate::ss58::to_ss58(account, network.ss58_prefix()));
+    }
+
+    Ok(())
+}
+
+async fn run_dump_storage(
+    config: AppConfig,
+    network: &str,
+    chain: &str,
+    pallet: &str,
+    item: &str,
+    account: Option<&str>,
+) -> Result<()> {
+    use crate::chain_client::{ChainClient, ParsedValue};
+    use crate::config::default_rpc_url;
+
+    let network = parse_network(network)?;
+    let chain = parse_chain(chain)?;
+
+    let rpc_urls = config.get_rpc_urls(network, chain);
+    let rpc_url = rpc_urls.first().map(|s| s.as_str()).unwrap_or_else(|| default_rpc_url(network, chain));
+
+    let client = ChainClient::connect(rpc_url, network, chain).await?;
+    let account = account.map(|a| client.parse_address(a)).transpose()?;
+    let value = client.dump_storage(pallet, item, account.as_ref()).await?;
+
+    match value {
+        ParsedValue::Balance(amount) => println!("{}::{} = {}", pallet, item, amount),
+        ParsedValue::AccountList(accounts) => {
+            println!("{}::{} = {} account(s):", pallet, item, accounts.len());
+            for account in accounts {
+                println!("  {}", crate::ss58::to_ss58(&account, network.ss58_prefix()));
+            }
+        }
+        ParsedValue::AuraAuthorityKeys(keys) => {
+            println!("{}::{} = {} key(s):", pallet, item, keys.len());
+            for key in keys {
+                println!("  {}", crate::ss58::to_ss58(&subxt::utils::AccountId32(key), network.ss58_prefix()));
+            }
+        }
     }
+
+    Ok(())
+}
+
+/// One chain's worth of `status` output, structured for `--output json` -
+/// mirrors the same balance/bond/candidate fields the text rendering prints.
+#[derive(serde::Serialize)]
+struct ChainStatusReport {
+    chain: String,
+    read_only: bool,
+    status: crate::chain_client::CollatorStatus,
+    balance: u128,
+    min_bond: u128,
+    lowest_candidate_bond: Option<u128>,
+    highest_candidate_bond: Option<u128>,
+    available_for_bond: u128,
+    can_beat_lowest_candidate: Option<bool>,
+    can_be_top_candidate: Option<bool>,
+    invulnerables: Vec<String>,
+    candidates: Vec<CandidateReport>,
+}
+
+#[derive(serde::Serialize)]
+struct CandidateReport {
+    account: String,
+    deposit: u128,
+    is_you: bool,
+}
+
+#[derive(serde::Serialize)]
+struct NetworkStatusReport {
+    network: String,
+    collator_address: String,
+    chains: Vec<ChainStatusEntry>,
 }
 
-async fn run_status(config: AppConfig) -> Result<()> {
+#[derive(serde::Serialize)]
+#[serde(untagged)]
+enum ChainStatusEntry {
+    Ok(ChainStatusReport),
+    Error { chain: String, error: String },
+}
+
+async fn run_status(config: AppConfig, output: OutputFormat) -> Result<()> {
     info!("Checking collator status across all chains (read-only)");
 
     use crate::chain_client::ChainClient;
     use crate::config::{chain_supports_proxy, default_rpc_url, Network, SystemChain};
 
+    let mut polkadot_report = NetworkStatusReport {
+        network: "polkadot".to_string(),
+        collator_address: config.polkadot_collator_address.clone(),
+        chains: Vec::new(),
+    };
+    let mut kusama_report = NetworkStatusReport {
+        network: "kusama".to_string(),
+        collator_address: config.kusama_collator_address.clone(),
+        chains: Vec::new(),
+    };
+
     let polkadot_chains = [
         SystemChain::AssetHub,
         SystemChain::BridgeHub,
@@ -205,13 +698,15 @@ async fn run_status(config: AppConfig) -> Result<()> {
         SystemChain::Encointer,
     ];
 
-    println!("\n=== Polkadot System Chains ===");
-    println!("Looking for collator: {}\n", config.polkadot_collator_address);
+    if output == OutputFormat::Text {
+        println!("\n=== Polkadot System Chains ===");
+        println!("Looking for collator: {}\n", config.polkadot_collator_address);
+    }
 
     for chain in polkadot_chains {
         let supports_proxy = chain_supports_proxy(chain);
         let read_only_marker = if !supports_proxy { " [READ-ONLY - no proxy support]" } else { "" };
-        
+
         let rpc_urls = config.get_rpc_urls(Network::Polkadot, chain);
         let rpc_url = rpc_urls.first().map(|s| s.as_str()).unwrap_or_else(|| default_rpc_url(Network::Polkadot, chain));
 
@@ -221,16 +716,43 @@ async fn run_status(config: AppConfig) -> Result<()> {
                 let status = client.get_collator_status(&account).await?;
                 let balance = client.get_free_balance(&account).await?;
                 let min_bond = client.get_candidacy_bond().await?;
-                
+
                 // Get invulnerables and candidates for display
                 let invulnerables = client.get_invulnerables().await?;
                 let candidates = client.get_candidates().await?;
-                
+
                 // Calculate competitive bond info
                 let lowest_candidate_bond = candidates.iter().filter(|c| c.deposit > 0).map(|c| c.deposit).min();
                 let highest_candidate_bond = candidates.iter().map(|c| c.deposit).max();
-                
+
                 let decimals = 10_000_000_000.0; // DOT decimals
+                let reserve = 10_000_000_000u128; // 1 DOT reserve
+                let available = balance.saturating_sub(reserve);
+
+                if output == OutputFormat::Json {
+                    polkadot_report.chains.push(ChainStatusEntry::Ok(ChainStatusReport {
+                        chain: chain.display_name(Network::Polkadot).to_string(),
+                        read_only: !supports_proxy,
+                        status,
+                        balance,
+                        min_bond,
+                        lowest_candidate_bond,
+                        highest_candidate_bond,
+                        available_for_bond: available,
+                        can_beat_lowest_candidate: lowest_candidate_bond.map(|lowest| available > lowest),
+                        can_be_top_candidate: highest_candidate_bond.map(|highest| available > highest),
+                        invulnerables: invulnerables.iter().map(|inv| inv.to_string()).collect(),
+                        candidates: candidates
+                            .iter()
+                            .map(|cand| CandidateReport {
+                                account: cand.who.to_string(),
+                                deposit: cand.deposit,
+                                is_you: cand.who == account,
+                            })
+                            .collect(),
+                    }));
+                    continue;
+                }
 
                 println!("  {}{}:", chain.display_name(Network::Polkadot), read_only_marker);
                 println!("    Your Status: {:?}", status);
@@ -246,12 +768,10 @@ async fn run_status(config: AppConfig) -> Result<()> {
                 if let Some(highest) = highest_candidate_bond {
                     println!("      - To be top candidate: {:.4} DOT", (highest + 1) as f64 / decimals);
                 }
-                
+
                 // Show if user can compete
-                let reserve = 10_000_000_000u128; // 1 DOT reserve
-                let available = balance.saturating_sub(reserve);
                 println!("    Your Available for Bond: {:.4} DOT (after 1 DOT reserve)", available as f64 / decimals);
-                
+
                 if let Some(lowest) = lowest_candidate_bond {
                     if available > lowest {
                         println!("    âœ“ Can beat lowest candidate");
@@ -268,7 +788,7 @@ async fn run_status(config: AppConfig) -> Result<()> {
                         println!("    âœ— Need {:.4} more DOT to be top candidate", needed as f64 / decimals);
                     }
                 }
-                
+
                 println!("    Invulnerables ({}):", invulnerables.len());
                 for inv in &invulnerables {
                     let marker = if inv == &account { " <-- YOU" } else { "" };
@@ -287,6 +807,13 @@ async fn run_status(config: AppConfig) -> Result<()> {
                 println!();
             }
             Err(e) => {
+                if output == OutputFormat::Json {
+                    polkadot_report.chains.push(ChainStatusEntry::Error {
+                        chain: chain.display_name(Network::Polkadot).to_string(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
                 println!(
                     "  {}: Error - {}",
                     chain.display_name(Network::Polkadot),
@@ -296,8 +823,10 @@ async fn run_status(config: AppConfig) -> Result<()> {
         }
     }
 
-    println!("\n=== Kusama System Chains ===");
-    println!("Looking for collator: {}\n", config.kusama_collator_address);
+    if output == OutputFormat::Text {
+        println!("\n=== Kusama System Chains ===");
+        println!("Looking for collator: {}\n", config.kusama_collator_address);
+    }
 
     for chain in kusama_chains {
         let supports_proxy = chain_supports_proxy(chain);
@@ -312,16 +841,43 @@ async fn run_status(config: AppConfig) -> Result<()> {
                 let status = client.get_collator_status(&account).await?;
                 let balance = client.get_free_balance(&account).await?;
                 let min_bond = client.get_candidacy_bond().await?;
-                
+
                 // Get invulnerables and candidates for display
                 let invulnerables = client.get_invulnerables().await?;
                 let candidates = client.get_candidates().await?;
-                
+
                 // Calculate competitive bond info
                 let lowest_candidate_bond = candidates.iter().filter(|c| c.deposit > 0).map(|c| c.deposit).min();
                 let highest_candidate_bond = candidates.iter().map(|c| c.deposit).max();
-                
+
                 let decimals = 1_000_000_000_000.0; // KSM decimals
+                let reserve = 100_000_000_000u128; // 0.1 KSM reserve
+                let available = balance.saturating_sub(reserve);
+
+                if output == OutputFormat::Json {
+                    kusama_report.chains.push(ChainStatusEntry::Ok(ChainStatusReport {
+                        chain: chain.display_name(Network::Kusama).to_string(),
+                        read_only: !supports_proxy,
+                        status,
+                        balance,
+                        min_bond,
+                        lowest_candidate_bond,
+                        highest_candidate_bond,
+                        available_for_bond: available,
+                        can_beat_lowest_candidate: lowest_candidate_bond.map(|lowest| available > lowest),
+                        can_be_top_candidate: highest_candidate_bond.map(|highest| available > highest),
+                        invulnerables: invulnerables.iter().map(|inv| inv.to_string()).collect(),
+                        candidates: candidates
+                            .iter()
+                            .map(|cand| CandidateReport {
+                                account: cand.who.to_string(),
+                                deposit: cand.deposit,
+                                is_you: cand.who == account,
+                            })
+                            .collect(),
+                    }));
+                    continue;
+                }
 
                 println!("  {}{}:", chain.display_name(Network::Kusama), read_only_marker);
                 println!("    Your Status: {:?}", status);
@@ -337,12 +893,10 @@ async fn run_status(config: AppConfig) -> Result<()> {
                 if let Some(highest) = highest_candidate_bond {
                     println!("      - To be top candidate: {:.4} KSM", (highest + 1) as f64 / decimals);
                 }
-                
+
                 // Show if user can compete
-                let reserve = 100_000_000_000u128; // 0.1 KSM reserve
-                let available = balance.saturating_sub(reserve);
                 println!("    Your Available for Bond: {:.4} KSM (after 0.1 KSM reserve)", available as f64 / decimals);
-                
+
                 if let Some(lowest) = lowest_candidate_bond {
                     if available > lowest {
                         println!("    âœ“ Can beat lowest candidate");
@@ -359,7 +913,7 @@ async fn run_status(config: AppConfig) -> Result<()> {
                         println!("    âœ— Need {:.4} more KSM to be top candidate", needed as f64 / decimals);
                     }
                 }
-                
+
                 println!("    Invulnerables ({}):", invulnerables.len());
                 for inv in &invulnerables {
                     let marker = if inv == &account { " <-- YOU" } else { "" };
@@ -378,38 +932,263 @@ async fn run_status(config: AppConfig) -> Result<()> {
                 println!();
             }
             Err(e) => {
+                if output == OutputFormat::Json {
+                    kusama_report.chains.push(ChainStatusEntry::Error {
+                        chain: chain.display_name(Network::Kusama).to_string(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
                 println!("  {}: Error - {}", chain.display_name(Network::Kusama), e);
             }
         }
     }
 
+    let mut testnet_reports = Vec::new();
+
+    // Testnets are opt-in: only report on one once a collator address is
+    // configured for it, same gate `CollatorMonitor::monitor_all_chains` uses.
+    if let Some(westend_address) = config.westend_collator_address.clone() {
+        let westend_chains = [
+            SystemChain::AssetHub,
+            SystemChain::BridgeHub,
+            SystemChain::Collectives,
+            SystemChain::Coretime,
+            SystemChain::People,
+            SystemChain::Glutton,
+        ];
+        testnet_reports.push(
+            run_status_for_network(&config, output, Network::Westend, westend_address, &westend_chains).await?,
+        );
+    }
+
+    if let Some(paseo_address) = config.paseo_collator_address.clone() {
+        let paseo_chains = [
+            SystemChain::AssetHub,
+            SystemChain::BridgeHub,
+            SystemChain::Coretime,
+            SystemChain::People,
+        ];
+        testnet_reports.push(
+            run_status_for_network(&config, output, Network::Paseo, paseo_address, &paseo_chains).await?,
+        );
+    }
+
+    if output == OutputFormat::Json {
+        let mut combined = vec![polkadot_report, kusama_report];
+        combined.extend(testnet_reports);
+        match serde_json::to_string(&combined) {
+            Ok(json) => println!("{}", json),
+            Err(e) => error!("Failed to serialize status report as JSON: {}", e),
+        }
+    }
+
     Ok(())
 }
 
-fn print_results(results: &[crate::monitor::MonitorResult]) {
+/// Status report for a single testnet network (Westend/Paseo) - split out from
+/// `run_status`'s Polkadot/Kusama blocks (which stay separate since their bond
+/// math differs) so adding a testnet doesn't mean copy-pasting that whole block
+/// a third and fourth time.
+async fn run_status_for_network(
+    config: &AppConfig,
+    output: OutputFormat,
+    network: crate::config::Network,
+    collator_address: String,
+    chains: &[crate::config::SystemChain],
+) -> Result<NetworkStatusReport> {
+    use crate::chain_client::ChainClient;
+    use crate::config::{chain_supports_proxy, default_rpc_url};
+
+    let mut report = NetworkStatusReport {
+        network: format!("{:?}", network).to_lowercase(),
+        collator_address: collator_address.clone(),
+        chains: Vec::new(),
+    };
+
+    if output == OutputFormat::Text {
+        println!("\n=== {:?} System Chains ===", network);
+        println!("Looking for collator: {}\n", collator_address);
+    }
+
+    let decimals = 10f64.powi(network.decimals() as i32);
+    let symbol = network.symbol();
+    let reserve = network.reserve_amount();
+
+    for &chain in chains {
+        let supports_proxy = chain_supports_proxy(chain);
+        let read_only_marker = if !supports_proxy { " [READ-ONLY - no proxy support]" } else { "" };
+
+        let rpc_urls = config.get_rpc_urls(network, chain);
+        let rpc_url = rpc_urls.first().map(|s| s.as_str()).unwrap_or_else(|| default_rpc_url(network, chain));
+
+        match ChainClient::connect(rpc_url, network, chain).await {
+            Ok(client) => {
+                let account = client.parse_address(&collator_address)?;
+                let status = client.get_collator_status(&account).await?;
+                let balance = client.get_free_balance(&account).await?;
+                let min_bond = client.get_candidacy_bond().await?;
+
+                let invulnerables = client.get_invulnerables().await?;
+                let candidates = client.get_candidates().await?;
+
+                let lowest_candidate_bond = candidates.iter().filter(|c| c.deposit > 0).map(|c| c.deposit).min();
+                let highest_candidate_bond = candidates.iter().map(|c| c.deposit).max();
+
+                let available = balance.saturating_sub(reserve);
+
+                if output == OutputFormat::Json {
+                    report.chains.push(ChainStatusEntry::Ok(ChainStatusReport {
+                        chain: chain.display_name(network).to_string(),
+                        read_only: !supports_proxy,
+                        status,
+                        balance,
+                        min_bond,
+                        lowest_candidate_bond,
+                        highest_candidate_bond,
+                        available_for_bond: available,
+                        can_beat_lowest_candidate: lowest_candidate_bond.map(|lowest| available > lowest),
+                        can_be_top_candidate: highest_candidate_bond.map(|highest| available > highest),
+                        invulnerables: invulnerables.iter().map(|inv| inv.to_string()).collect(),
+                        candidates: candidates
+                            .iter()
+                            .map(|cand| CandidateReport {
+                                account: cand.who.to_string(),
+                                deposit: cand.deposit,
+                                is_you: cand.who == account,
+                            })
+                            .collect(),
+                    }));
+                    continue;
+                }
+
+                println!("  {}{}:", chain.display_name(network), read_only_marker);
+                println!("    Your Status: {:?}", status);
+                println!("    Your Balance: {:.4} {}", balance as f64 / decimals, symbol);
+                println!("    Bond Requirements:");
+                println!("      - Minimum to register: {:.4} {}", min_bond as f64 / decimals, symbol);
+                if let Some(lowest) = lowest_candidate_bond {
+                    println!("      - To beat lowest candidate: {:.4} {}", (lowest + 1) as f64 / decimals, symbol);
+                }
+                if let Some(highest) = highest_candidate_bond {
+                    println!("      - To be top candidate: {:.4} {}", (highest + 1) as f64 / decimals, symbol);
+                }
+
+                println!("    Your Available for Bond: {:.4} {} (after 1 {} reserve)", available as f64 / decimals, symbol, symbol);
+
+                if let Some(lowest) = lowest_candidate_bond {
+                    if available > lowest {
+                        println!("    ✓ Can beat lowest candidate");
+                    } else {
+                        let needed = lowest.saturating_sub(available) + 1;
+                        println!("    ✗ Need {:.4} more {} to beat lowest candidate", needed as f64 / decimals, symbol);
+                    }
+                }
+                if let Some(highest) = highest_candidate_bond {
+                    if available > highest {
+                        println!("    ✓ Can be top candidate");
+                    } else {
+                        let needed = highest.saturating_sub(available) + 1;
+                        println!("    ✗ Need {:.4} more {} to be top candidate", needed as f64 / decimals, symbol);
+                    }
+                }
+
+                println!("    Invulnerables ({}):", invulnerables.len());
+                for inv in &invulnerables {
+                    let marker = if inv == &account { " <-- YOU" } else { "" };
+                    println!("      - {}{}", inv, marker);
+                }
+                println!("    Candidates ({}):", candidates.len());
+                for cand in &candidates {
+                    let marker = if cand.who == account { " <-- YOU" } else { "" };
+                    println!("      - {} (bond: {:.4} {}){}", cand.who, cand.deposit as f64 / decimals, symbol, marker);
+                }
+                println!();
+            }
+            Err(e) => {
+                if output == OutputFormat::Json {
+                    report.chains.push(ChainStatusEntry::Error {
+                        chain: chain.display_name(network).to_string(),
+                        error: e.to_string(),
+                    });
+                    continue;
+                }
+                println!("  {}: Error - {}", chain.display_name(network), e);
+            }
+        }
+    }
+
+    Ok(report)
+}
+
+fn print_results(results: &[crate::monitor::MonitorResult], output: OutputFormat) {
+    if output == OutputFormat::Json {
+        match serde_json::to_string(&results) {
+            Ok(json) => println!("{}", json),
+            Err(e) => error!("Failed to serialize monitoring results as JSON: {}", e),
+        }
+        return;
+    }
+
     println!("\n=== Monitoring Results ===\n");
 
     for result in results {
         let status_str = match &result.status {
             MonitorStatus::AlreadyCollator(s) => format!("âœ“ Already collator: {:?}", s),
-            MonitorStatus::RegisteredAsCandidate { bond, tx_hash } => {
-                format!("âœ“ Registered with bond {} (tx: {})", bond, tx_hash)
+            MonitorStatus::RegisteredAsCandidate { bond, tx_hash, tip } => {
+                format!("âœ“ Registered with bond {} (tx: {}, tip: {})", bond, tx_hash, tip)
+            }
+            MonitorStatus::RegisteredByEviction { bond, evicted, evicted_bond, tx_hash, tip } => {
+                format!(
+                    "âœ“ Registered with bond {} by evicting {} (bond {}) (tx: {}, tip: {})",
+                    bond, evicted, evicted_bond, tx_hash, tip
+                )
+            }
+            MonitorStatus::UpdatedBond { old_bond, new_bond, tx_hash, tip } => {
+                format!("âœ“ Updated bond {} â†’ {} (tx: {}, tip: {})", old_bond, new_bond, tx_hash, tip)
             }
-            MonitorStatus::UpdatedBond { old_bond, new_bond, tx_hash } => {
-                format!("âœ“ Updated bond {} â†’ {} (tx: {})", old_bond, new_bond, tx_hash)
+            MonitorStatus::BondRebalanced { old_bond, new_bond, rank } => {
+                format!("âš–ï¸ Bond rebalanced {} â†’ {} (was rank #{})", old_bond, new_bond, rank)
             }
             MonitorStatus::InsufficientFunds { available, required } => {
                 format!("âœ— Insufficient funds: have {}, need {}", available, required)
             }
-            MonitorStatus::CannotCompete { available, lowest_candidate, needed } => {
-                format!("âœ— Cannot compete: have {}, lowest candidate {}, need {} more", 
-                    available, lowest_candidate, needed)
+            MonitorStatus::NotCompetitive { available, required_bond } => {
+                format!("âœ— Not competitive: have {}, need {} to clear the pool threshold",
+                    available, required_bond)
             }
             MonitorStatus::ManualActionRequired { reason, current_status } => {
                 format!("ðŸ”§ Manual action required: {} (current: {:?})", reason, current_status)
             }
+            MonitorStatus::Delinquent { slots_missed, expected_interval_secs } => {
+                format!(
+                    "ðŸ¢ Delinquent: no block authored in ~{} slots (expected every {}s)",
+                    slots_missed, expected_interval_secs
+                )
+            }
+            MonitorStatus::SkippingScheduledSlots { authored, expected, window } => {
+                format!(
+                    "ðŸ¢ Skipping scheduled slots: authored {} of {} expected over the last {} slots",
+                    authored, expected, window
+                )
+            }
             MonitorStatus::Error(e) => format!("âœ— Error: {}", e),
             MonitorStatus::Skipped(reason) => format!("- Skipped: {}", reason),
+            MonitorStatus::DryRun { call, bond, signed_payload_hex } => {
+                format!(
+                    "[dry run] signed {}{} (payload: {})",
+                    call,
+                    bond.map(|b| format!(", bond {}", b)).unwrap_or_default(),
+                    signed_payload_hex
+                )
+            }
+            MonitorStatus::OfflinePayloadPrepared { path, bond } => {
+                format!(
+                    "[sign-only] wrote offline payload{} to {} - sign it on the air-gapped machine, then `broadcast`",
+                    bond.map(|b| format!(" for bond {}", b)).unwrap_or_default(),
+                    path
+                )
+            }
         };
 
         println!("  {}: {}", result.chain_name, status_str);
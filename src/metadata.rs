@@ -30,3 +30,51 @@ pub mod people_kusama {}
 
 #[subxt::subxt(runtime_metadata_path = "metadata/encointer-kusama.scale")]
 pub mod encointer_kusama {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/asset-hub-westend.scale")]
+pub mod asset_hub_westend {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/bridge-hub-westend.scale")]
+pub mod bridge_hub_westend {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/collectives-westend.scale")]
+pub mod collectives_westend {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/coretime-westend.scale")]
+pub mod coretime_westend {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/people-westend.scale")]
+pub mod people_westend {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/glutton-westend.scale")]
+pub mod glutton_westend {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/asset-hub-paseo.scale")]
+pub mod asset_hub_paseo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/bridge-hub-paseo.scale")]
+pub mod bridge_hub_paseo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/coretime-paseo.scale")]
+pub mod coretime_paseo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/people-paseo.scale")]
+pub mod people_paseo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/asset-hub-rococo.scale")]
+pub mod asset_hub_rococo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/bridge-hub-rococo.scale")]
+pub mod bridge_hub_rococo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/collectives-rococo.scale")]
+pub mod collectives_rococo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/coretime-rococo.scale")]
+pub mod coretime_rococo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/people-rococo.scale")]
+pub mod people_rococo {}
+
+#[subxt::subxt(runtime_metadata_path = "metadata/glutton-rococo.scale")]
+pub mod glutton_rococo {}
@@ -0,0 +1,108 @@
+//! Detect drift between the `.scale` metadata this binary was compiled
+//! against and what a chain's live runtime actually serves.
+//!
+//! The `#[subxt::subxt(runtime_metadata_path = ...)]` modules in
+//! [`metadata`](crate::metadata) bake in a fixed snapshot, but system-chain
+//! runtimes upgrade regularly - a storage item renamed or a pallet removed
+//! silently breaks the typed queries in
+//! [`block_tracker`](crate::block_tracker) without raising anything louder
+//! than an RPC error on first use. This instead compares, up front, a digest
+//! of the storage/call items the monitor actually depends on between the
+//! embedded metadata and a freshly connected chain, so drift is caught
+//! before a signing path is ever attempted against it rather than after.
+//!
+//! Scoped deliberately to *names*, not full recursive type structure: it
+//! catches the common breaking cases (an item renamed or removed, a pallet
+//! dropped) without tripping on an unrelated pallet's internal type changes
+//! that don't affect what this monitor reads or calls.
+
+use blake2::{Blake2b512, Digest};
+use subxt::Metadata;
+
+use crate::config::{Network, SystemChain};
+use crate::metadata::*;
+
+/// Pallets whose storage/call shape the monitor actually depends on:
+/// `System` for account/nonce queries every flow needs, `Balances` for
+/// reserve/lock checks, `Proxy` for the proxy-signed submission path, and
+/// `CollatorSelection` for candidates/invulnerables. A chain missing one of
+/// these (e.g. no `Proxy` pallet on a read-only BridgeHub) just contributes
+/// nothing to the digest rather than counting as drift.
+const WATCHED_PALLETS: &[&str] = &["System", "Balances", "Proxy", "CollatorSelection"];
+
+/// A stable digest over the watched pallets' storage/call item names, as
+/// seen in a particular piece of metadata (compiled-in or freshly fetched).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MetadataDigest([u8; 64]);
+
+/// Compute the digest for the watched subset of `metadata`.
+pub fn digest(metadata: &Metadata) -> MetadataDigest {
+    let mut hasher = Blake2b512::new();
+
+    for pallet_name in WATCHED_PALLETS {
+        let Some(pallet) = metadata.pallet_by_name(pallet_name) else {
+            continue;
+        };
+        hasher.update(pallet_name.as_bytes());
+
+        let mut storage_names: Vec<&str> =
+            pallet.storage().map(|s| s.entries().iter().map(|e| e.name()).collect()).unwrap_or_default();
+        storage_names.sort_unstable();
+        for name in storage_names {
+            hasher.update(name.as_bytes());
+        }
+
+        let mut call_names: Vec<&str> =
+            pallet.call_variants().map(|variants| variants.iter().map(|v| v.name.as_str()).collect()).unwrap_or_default();
+        call_names.sort_unstable();
+        for name in call_names {
+            hasher.update(name.as_bytes());
+        }
+    }
+
+    let mut out = [0u8; 64];
+    out.copy_from_slice(&hasher.finalize());
+    MetadataDigest(out)
+}
+
+/// The digest of the metadata this binary was compiled against for a given
+/// `(network, chain)` pair, or `None` if that pair isn't one of the chains a
+/// `#[subxt::subxt(...)]` module was generated for.
+pub fn embedded_digest(network: Network, chain: SystemChain) -> Option<MetadataDigest> {
+    let metadata = match (network, chain) {
+        (Network::Polkadot, SystemChain::AssetHub) => asset_hub_polkadot::metadata(),
+        (Network::Polkadot, SystemChain::BridgeHub) => bridge_hub_polkadot::metadata(),
+        (Network::Polkadot, SystemChain::Collectives) => collectives_polkadot::metadata(),
+        (Network::Polkadot, SystemChain::Coretime) => coretime_polkadot::metadata(),
+        (Network::Polkadot, SystemChain::People) => people_polkadot::metadata(),
+
+        (Network::Kusama, SystemChain::AssetHub) => asset_hub_kusama::metadata(),
+        (Network::Kusama, SystemChain::BridgeHub) => bridge_hub_kusama::metadata(),
+        (Network::Kusama, SystemChain::Coretime) => coretime_kusama::metadata(),
+        (Network::Kusama, SystemChain::People) => people_kusama::metadata(),
+        (Network::Kusama, SystemChain::Encointer) => encointer_kusama::metadata(),
+
+        (Network::Westend, SystemChain::AssetHub) => asset_hub_westend::metadata(),
+        (Network::Westend, SystemChain::BridgeHub) => bridge_hub_westend::metadata(),
+        (Network::Westend, SystemChain::Collectives) => collectives_westend::metadata(),
+        (Network::Westend, SystemChain::Coretime) => coretime_westend::metadata(),
+        (Network::Westend, SystemChain::People) => people_westend::metadata(),
+        (Network::Westend, SystemChain::Glutton) => glutton_westend::metadata(),
+
+        (Network::Paseo, SystemChain::AssetHub) => asset_hub_paseo::metadata(),
+        (Network::Paseo, SystemChain::BridgeHub) => bridge_hub_paseo::metadata(),
+        (Network::Paseo, SystemChain::Coretime) => coretime_paseo::metadata(),
+        (Network::Paseo, SystemChain::People) => people_paseo::metadata(),
+
+        (Network::Rococo, SystemChain::AssetHub) => asset_hub_rococo::metadata(),
+        (Network::Rococo, SystemChain::BridgeHub) => bridge_hub_rococo::metadata(),
+        (Network::Rococo, SystemChain::Collectives) => collectives_rococo::metadata(),
+        (Network::Rococo, SystemChain::Coretime) => coretime_rococo::metadata(),
+        (Network::Rococo, SystemChain::People) => people_rococo::metadata(),
+        (Network::Rococo, SystemChain::Glutton) => glutton_rococo::metadata(),
+
+        _ => return None,
+    };
+
+    Some(digest(&metadata))
+}
@@ -0,0 +1,423 @@
+//! Prometheus scrape endpoint exposing the same slot data fed into
+//! [`crate::slack::SlackNotifier::send_status_summary`], so operators can drive
+//! Grafana dashboards and external alerting off the monitor instead of relying
+//! solely on rate-limited Slack messages - mirroring how validator tooling
+//! exposes delinquency and vote-distance metrics for scraping.
+
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+use anyhow::{Context, Result};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tracing::{info, warn};
+
+use crate::chain_client::BlsG1PublicKey;
+use crate::slack::ChainSlotInfo;
+
+/// Upper bounds (in seconds) of the `block_authoring_interval_seconds` histogram
+/// buckets, chosen to straddle Aura's ~6s slot cadence up to the 30-minute
+/// "no blocks" alert threshold in [`crate::block_tracker`].
+const AUTHORING_INTERVAL_BUCKETS_SECS: [f64; 6] = [6.0, 12.0, 24.0, 60.0, 300.0, 1800.0];
+
+/// Cumulative bucket counts for one chain's inter-block authoring interval,
+/// in the same layout Prometheus expects: `bucket_counts[i]` holds the number
+/// of observations `<= AUTHORING_INTERVAL_BUCKETS_SECS[i]`.
+#[derive(Debug, Default)]
+struct AuthoringIntervalHistogram {
+    bucket_counts: [u64; AUTHORING_INTERVAL_BUCKETS_SECS.len()],
+    sum: f64,
+    count: u64,
+}
+
+impl AuthoringIntervalHistogram {
+    fn observe(&mut self, seconds: f64) {
+        for (i, &bound) in AUTHORING_INTERVAL_BUCKETS_SECS.iter().enumerate() {
+            if seconds <= bound {
+                self.bucket_counts[i] += 1;
+            }
+        }
+        self.sum += seconds;
+        self.count += 1;
+    }
+}
+
+/// Per chain [`crate::block_tracker::BlockTracker`] health, refreshed as blocks
+/// are authored, connections flap, or the collator's on-chain status changes.
+#[derive(Debug, Default)]
+struct ChainHealth {
+    is_connected: bool,
+    last_authored: Option<Instant>,
+    status_label: Option<String>,
+    deposit: Option<f64>,
+}
+
+/// Per chain/collator gauge values, refreshed each time a [`ChainSlotInfo`] is
+/// collected.
+#[derive(Debug, Clone, Default)]
+struct SlotGauges {
+    position: Option<usize>,
+    total_candidates: usize,
+    max_candidates: Option<u32>,
+    in_active_set: bool,
+    distance_from_last: Option<f64>,
+    is_invulnerable: bool,
+    seconds_since_last_block: Option<f64>,
+}
+
+/// Per chain/RPC-endpoint gauge values, refreshed every connect attempt so
+/// the failover scoreboard in [`crate::block_tracker::BlockTracker`] is
+/// visible to Grafana, not just the logs.
+#[derive(Debug, Clone, Default)]
+struct RpcEndpointGauge {
+    is_selected: bool,
+    circuit_open: bool,
+    ewma_latency_ms: Option<f64>,
+}
+
+/// Per chain BEEFY BLS committee aggregate key state, refreshed each bridge
+/// health check - see [`MetricsRegistry::record_beefy_aggregate`].
+#[derive(Debug, Clone, Default)]
+struct BeefyAggregateState {
+    last_seen: Option<BlsG1PublicKey>,
+    changes_total: u64,
+}
+
+/// In-memory registry of everything the `/metrics` endpoint exposes. Cheap to
+/// clone via `Arc` and safe to update from multiple chains concurrently.
+#[derive(Debug, Default)]
+pub struct MetricsRegistry {
+    slots: Mutex<HashMap<(String, String), SlotGauges>>,
+    alerts_sent_total: Mutex<HashMap<String, u64>>,
+    outstanding_issues: Mutex<u64>,
+    chain_health: Mutex<HashMap<String, ChainHealth>>,
+    authoring_intervals: Mutex<HashMap<String, AuthoringIntervalHistogram>>,
+    rpc_failovers_total: Mutex<HashMap<String, u64>>,
+    rpc_endpoints: Mutex<HashMap<(String, String), RpcEndpointGauge>>,
+    beefy_aggregate: Mutex<HashMap<String, BeefyAggregateState>>,
+}
+
+impl MetricsRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the latest slot snapshot for `chain_name`/`collator_address`.
+    pub fn record_slot(&self, collator_address: &str, slot: &ChainSlotInfo) {
+        let in_active_set = match (slot.position, slot.max_candidates) {
+            (Some(pos), Some(max)) => !(max > 0 && pos > max as usize),
+            _ => slot.is_invulnerable,
+        };
+
+        let distance_from_last = slot.distance_from_last.map(|dist| {
+            let divisor = 10u128.pow(slot.decimals) as f64;
+            dist as f64 / divisor
+        });
+
+        let gauges = SlotGauges {
+            position: slot.position,
+            total_candidates: slot.total_candidates,
+            max_candidates: slot.max_candidates,
+            in_active_set,
+            distance_from_last,
+            is_invulnerable: slot.is_invulnerable,
+            seconds_since_last_block: slot.last_block_time.map(|d| d.as_secs_f64()),
+        };
+
+        self.slots
+            .lock()
+            .unwrap()
+            .insert((slot.chain_name.clone(), collator_address.to_string()), gauges);
+    }
+
+    /// Increment `alerts_sent_total{type="<alert_type>"}`.
+    pub fn incr_alert(&self, alert_type: &str) {
+        *self
+            .alerts_sent_total
+            .lock()
+            .unwrap()
+            .entry(alert_type.to_string())
+            .or_insert(0) += 1;
+    }
+
+    /// Set the `outstanding_issues` gauge to the current count of chains with
+    /// an unresolved alert.
+    pub fn set_outstanding_issues(&self, count: u64) {
+        *self.outstanding_issues.lock().unwrap() = count;
+    }
+
+    /// Record this cycle's BEEFY BLS committee aggregate key for `chain_name`,
+    /// bumping `beefy_aggregate_key_changes_total` if it differs from the
+    /// last one observed. `None` means the chain hasn't migrated to BLS yet
+    /// (and leaves `beefy_aggregate_key_present` at 0, with no change counted).
+    pub fn record_beefy_aggregate(&self, chain_name: &str, aggregate: Option<BlsG1PublicKey>) {
+        let mut states = self.beefy_aggregate.lock().unwrap();
+        let entry = states.entry(chain_name.to_string()).or_default();
+        if let Some(aggregate) = aggregate {
+            if entry.last_seen.is_some_and(|prev| prev != aggregate) {
+                entry.changes_total += 1;
+            }
+            entry.last_seen = Some(aggregate);
+        }
+    }
+
+    /// Set the `is_connected` gauge for `chain_name`.
+    pub fn set_connection_status(&self, chain_name: &str, is_connected: bool) {
+        self.chain_health
+            .lock()
+            .unwrap()
+            .entry(chain_name.to_string())
+            .or_default()
+            .is_connected = is_connected;
+    }
+
+    /// Record that `chain_name` authored a block, folding the time since the
+    /// previous authored block (if any) into `block_authoring_interval_seconds`.
+    pub fn record_authored_block(&self, chain_name: &str, previous_authored: Option<Instant>) {
+        let now = Instant::now();
+        if let Some(previous) = previous_authored {
+            self.authoring_intervals
+                .lock()
+                .unwrap()
+                .entry(chain_name.to_string())
+                .or_default()
+                .observe(now.duration_since(previous).as_secs_f64());
+        }
+
+        self.chain_health
+            .lock()
+            .unwrap()
+            .entry(chain_name.to_string())
+            .or_default()
+            .last_authored = Some(now);
+    }
+
+    /// Set the collator status enum gauge and, for `Candidate`, the deposit gauge
+    /// (in whole tokens) for `chain_name`.
+    pub fn set_collator_status(&self, chain_name: &str, status_label: &str, deposit: Option<f64>) {
+        let mut health = self.chain_health.lock().unwrap();
+        let entry = health.entry(chain_name.to_string()).or_default();
+        entry.status_label = Some(status_label.to_string());
+        entry.deposit = deposit;
+    }
+
+    /// Increment `rpc_failovers_total{chain_name}` - called when a connection
+    /// attempt succeeds on a non-primary RPC endpoint.
+    pub fn incr_rpc_failover(&self, chain_name: &str) {
+        *self
+            .rpc_failovers_total
+            .lock()
+            .unwrap()
+            .entry(chain_name.to_string())
+            .or_insert(0) += 1;
+    }
+
+    /// Set the per-endpoint failover scoreboard gauges (`chain_name`/`url`)
+    /// - which endpoint is currently selected, whether its circuit is open,
+    /// and its connect+first-block latency EWMA.
+    pub fn set_rpc_endpoint_health(
+        &self,
+        chain_name: &str,
+        url: &str,
+        is_selected: bool,
+        circuit_open: bool,
+        ewma_latency_ms: Option<f64>,
+    ) {
+        self.rpc_endpoints.lock().unwrap().insert(
+            (chain_name.to_string(), url.to_string()),
+            RpcEndpointGauge { is_selected, circuit_open, ewma_latency_ms },
+        );
+    }
+
+    /// Render the registry in Prometheus text exposition format.
+    fn render(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP collator_position Position in the candidate list, 1-indexed by bond descending (absent if not a candidate).\n");
+        out.push_str("# TYPE collator_position gauge\n");
+        out.push_str("# HELP collator_total_candidates Total number of candidates in the pool.\n");
+        out.push_str("# TYPE collator_total_candidates gauge\n");
+        out.push_str("# HELP collator_max_candidates Desired/max candidate pool size.\n");
+        out.push_str("# TYPE collator_max_candidates gauge\n");
+        out.push_str("# HELP collator_in_active_set Whether the collator is inside the selected set (1) or not (0).\n");
+        out.push_str("# TYPE collator_in_active_set gauge\n");
+        out.push_str("# HELP collator_distance_from_last Bond distance from the lowest candidate, in whole tokens.\n");
+        out.push_str("# TYPE collator_distance_from_last gauge\n");
+        out.push_str("# HELP collator_is_invulnerable Whether the collator is an invulnerable (1) or not (0).\n");
+        out.push_str("# TYPE collator_is_invulnerable gauge\n");
+        out.push_str("# HELP seconds_since_last_block Seconds since the collator last authored a block.\n");
+        out.push_str("# TYPE seconds_since_last_block gauge\n");
+
+        let slots = self.slots.lock().unwrap();
+        for ((chain_name, collator_address), gauges) in slots.iter() {
+            let labels = format!("chain_name=\"{}\",collator_address=\"{}\"", chain_name, collator_address);
+
+            if let Some(position) = gauges.position {
+                out.push_str(&format!("collator_position{{{}}} {}\n", labels, position));
+            }
+            out.push_str(&format!(
+                "collator_total_candidates{{{}}} {}\n",
+                labels, gauges.total_candidates
+            ));
+            if let Some(max_candidates) = gauges.max_candidates {
+                out.push_str(&format!("collator_max_candidates{{{}}} {}\n", labels, max_candidates));
+            }
+            out.push_str(&format!(
+                "collator_in_active_set{{{}}} {}\n",
+                labels,
+                gauges.in_active_set as u8
+            ));
+            if let Some(distance) = gauges.distance_from_last {
+                out.push_str(&format!("collator_distance_from_last{{{}}} {}\n", labels, distance));
+            }
+            out.push_str(&format!(
+                "collator_is_invulnerable{{{}}} {}\n",
+                labels,
+                gauges.is_invulnerable as u8
+            ));
+            if let Some(seconds) = gauges.seconds_since_last_block {
+                out.push_str(&format!("seconds_since_last_block{{{}}} {}\n", labels, seconds));
+            }
+        }
+        drop(slots);
+
+        out.push_str("# HELP alerts_sent_total Total alerts sent, by type.\n");
+        out.push_str("# TYPE alerts_sent_total counter\n");
+        for (alert_type, count) in self.alerts_sent_total.lock().unwrap().iter() {
+            out.push_str(&format!("alerts_sent_total{{type=\"{}\"}} {}\n", alert_type, count));
+        }
+
+        out.push_str("# HELP outstanding_issues Number of chains with an unresolved alert.\n");
+        out.push_str("# TYPE outstanding_issues gauge\n");
+        out.push_str(&format!("outstanding_issues {}\n", self.outstanding_issues.lock().unwrap()));
+
+        out.push_str("# HELP block_tracker_connected Whether the block tracker's RPC subscription for a chain is connected (1) or not (0).\n");
+        out.push_str("# TYPE block_tracker_connected gauge\n");
+        out.push_str("# HELP block_tracker_seconds_since_last_authored Seconds since the block tracker last saw the collator author a block on this chain.\n");
+        out.push_str("# TYPE block_tracker_seconds_since_last_authored gauge\n");
+        out.push_str("# HELP collator_status Collator status on this chain, as one series labeled by the current status with value 1.\n");
+        out.push_str("# TYPE collator_status gauge\n");
+        out.push_str("# HELP collator_candidate_deposit_tokens Candidate bond posted on this chain, in whole tokens (absent if not a candidate).\n");
+        out.push_str("# TYPE collator_candidate_deposit_tokens gauge\n");
+
+        let health = self.chain_health.lock().unwrap();
+        for (chain_name, health) in health.iter() {
+            let labels = format!("chain_name=\"{}\"", chain_name);
+            out.push_str(&format!(
+                "block_tracker_connected{{{}}} {}\n",
+                labels, health.is_connected as u8
+            ));
+            if let Some(last_authored) = health.last_authored {
+                out.push_str(&format!(
+                    "block_tracker_seconds_since_last_authored{{{}}} {}\n",
+                    labels,
+                    last_authored.elapsed().as_secs_f64()
+                ));
+            }
+            if let Some(status_label) = &health.status_label {
+                out.push_str(&format!(
+                    "collator_status{{{},status=\"{}\"}} 1\n",
+                    labels, status_label
+                ));
+            }
+            if let Some(deposit) = health.deposit {
+                out.push_str(&format!("collator_candidate_deposit_tokens{{{}}} {}\n", labels, deposit));
+            }
+        }
+        drop(health);
+
+        out.push_str("# HELP rpc_failovers_total Total number of times a chain's block tracker connected to a non-primary RPC endpoint.\n");
+        out.push_str("# TYPE rpc_failovers_total counter\n");
+        for (chain_name, count) in self.rpc_failovers_total.lock().unwrap().iter() {
+            out.push_str(&format!("rpc_failovers_total{{chain_name=\"{}\"}} {}\n", chain_name, count));
+        }
+
+        out.push_str("# HELP rpc_endpoint_selected 1 if this RPC endpoint is currently selected for the chain, 0 otherwise.\n");
+        out.push_str("# TYPE rpc_endpoint_selected gauge\n");
+        out.push_str("# HELP rpc_endpoint_circuit_open 1 if this RPC endpoint's circuit breaker is currently open, 0 otherwise.\n");
+        out.push_str("# TYPE rpc_endpoint_circuit_open gauge\n");
+        out.push_str("# HELP rpc_endpoint_latency_ms EWMA connect+first-block latency for this RPC endpoint, in milliseconds.\n");
+        out.push_str("# TYPE rpc_endpoint_latency_ms gauge\n");
+        for ((chain_name, url), gauge) in self.rpc_endpoints.lock().unwrap().iter() {
+            let labels = format!("chain_name=\"{}\",url=\"{}\"", chain_name, url);
+            out.push_str(&format!("rpc_endpoint_selected{{{}}} {}\n", labels, gauge.is_selected as u8));
+            out.push_str(&format!("rpc_endpoint_circuit_open{{{}}} {}\n", labels, gauge.circuit_open as u8));
+            if let Some(ms) = gauge.ewma_latency_ms {
+                out.push_str(&format!("rpc_endpoint_latency_ms{{{}}} {}\n", labels, ms));
+            }
+        }
+
+        out.push_str("# HELP block_authoring_interval_seconds Time between consecutive blocks authored by the collator on a chain.\n");
+        out.push_str("# TYPE block_authoring_interval_seconds histogram\n");
+        for (chain_name, histogram) in self.authoring_intervals.lock().unwrap().iter() {
+            let labels = format!("chain_name=\"{}\"", chain_name);
+            for (bound, cumulative) in AUTHORING_INTERVAL_BUCKETS_SECS.iter().zip(histogram.bucket_counts.iter()) {
+                out.push_str(&format!(
+                    "block_authoring_interval_seconds_bucket{{{},le=\"{}\"}} {}\n",
+                    labels, bound, cumulative
+                ));
+            }
+            out.push_str(&format!(
+                "block_authoring_interval_seconds_bucket{{{},le=\"+Inf\"}} {}\n",
+                labels, histogram.count
+            ));
+            out.push_str(&format!("block_authoring_interval_seconds_sum{{{}}} {}\n", labels, histogram.sum));
+            out.push_str(&format!("block_authoring_interval_seconds_count{{{}}} {}\n", labels, histogram.count));
+        }
+
+        out.push_str("# HELP beefy_aggregate_key_present 1 if this chain's BEEFY committee has migrated to BLS12-381 (an aggregate key is available), 0 otherwise.\n");
+        out.push_str("# TYPE beefy_aggregate_key_present gauge\n");
+        out.push_str("# HELP beefy_aggregate_key_changes_total Total number of times the BEEFY BLS committee aggregate key has changed for this chain.\n");
+        out.push_str("# TYPE beefy_aggregate_key_changes_total counter\n");
+        for (chain_name, state) in self.beefy_aggregate.lock().unwrap().iter() {
+            let labels = format!("chain_name=\"{}\"", chain_name);
+            out.push_str(&format!("beefy_aggregate_key_present{{{}}} {}\n", labels, state.last_seen.is_some() as u8));
+            out.push_str(&format!("beefy_aggregate_key_changes_total{{{}}} {}\n", labels, state.changes_total));
+        }
+
+        out
+    }
+}
+
+/// Serve `/metrics` on `bind_addr` until the process exits. Intended to be
+/// spawned as a background task from `run_watch`; any connection error is
+/// logged and the listener keeps serving.
+pub async fn serve(registry: Arc<MetricsRegistry>, bind_addr: SocketAddr) -> Result<()> {
+    let listener = TcpListener::bind(bind_addr)
+        .await
+        .with_context(|| format!("failed to bind metrics endpoint on {}", bind_addr))?;
+    info!("Metrics endpoint listening on http://{}/metrics", bind_addr);
+
+    loop {
+        let (mut stream, _) = match listener.accept().await {
+            Ok(conn) => conn,
+            Err(e) => {
+                warn!("Failed to accept metrics connection: {}", e);
+                continue;
+            }
+        };
+        let registry = registry.clone();
+
+        tokio::spawn(async move {
+            // We only serve a single fixed resource, so the request line/headers
+            // are read and discarded rather than parsed.
+            let mut buf = [0u8; 1024];
+            if stream.read(&mut buf).await.is_err() {
+                return;
+            }
+
+            let body = registry.render();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                body.len(),
+                body
+            );
+
+            if let Err(e) = stream.write_all(response.as_bytes()).await {
+                warn!("Failed to write metrics response: {}", e);
+            }
+        });
+    }
+}
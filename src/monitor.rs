@@ -1,15 +1,31 @@
 //! Monitoring logic for collator status and automatic re-registration.
 
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
 use anyhow::{Context, Result};
+use serde::Serialize;
 use subxt::utils::AccountId32;
 use tracing::{debug, error, info, warn};
 
+use crate::authorship_tracker::AuthorshipTracker;
 use crate::block_tracker::BlockTracker;
 use crate::chain_client::{ChainClient, CollatorStatus};
+use crate::collator_events::CollatorEventWatcher;
 use crate::config::{chain_supports_proxy, default_rpc_url, AppConfig, Network, SystemChain};
+use crate::error::CollatorError;
+use crate::keystore::{import_from_str, EncryptedFileKeystore, Keystore};
+use crate::metrics::MetricsRegistry;
+use crate::shutdown::ShutdownToken;
+use crate::signer::{InMemorySigner, RemoteHttpSigner, Signer};
 use crate::slack::{SlackNotifier, ChainSlotInfo};
 
+/// Format an account under `network`'s own SS58 prefix, instead of
+/// `AccountId32`'s `Display` impl, which always assumes one fixed network
+/// regardless of which chain the account actually came from - see `crate::ss58`.
+fn format_account(account: &AccountId32, network: Network) -> String {
+    crate::ss58::to_ss58(account, network.ss58_prefix())
+}
+
 /// Format a balance with proper decimal places and symbol
 fn format_balance(balance: u128, decimals: u32, symbol: &str) -> String {
     let divisor = 10u128.pow(decimals);
@@ -27,60 +43,248 @@ fn format_balance(balance: u128, decimals: u32, symbol: &str) -> String {
 }
 
 /// Result of monitoring a single chain
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct MonitorResult {
     pub chain_name: String,
     pub status: MonitorStatus,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
 pub enum MonitorStatus {
     /// Already a collator (invulnerable or candidate)
     AlreadyCollator(CollatorStatus),
-    /// Successfully registered as candidate
-    RegisteredAsCandidate { bond: u128, tx_hash: String },
+    /// Successfully registered as candidate into an open slot
+    RegisteredAsCandidate { bond: u128, tx_hash: String, tip: u128 },
+    /// Successfully registered as candidate by evicting the previous lowest bidder
+    RegisteredByEviction { bond: u128, evicted: String, evicted_bond: u128, tx_hash: String, tip: u128 },
     /// Successfully updated bond to higher amount
-    UpdatedBond { old_bond: u128, new_bond: u128, tx_hash: String },
+    UpdatedBond { old_bond: u128, new_bond: u128, tx_hash: String, tip: u128 },
+    /// Topped up the bond because our rank in a full candidate pool had slipped
+    /// toward the cutoff, not because of a routine "use the spare balance" update
+    BondRebalanced { old_bond: u128, new_bond: u128, rank: usize },
     /// Could not register due to insufficient funds for minimum bond
     InsufficientFunds { available: u128, required: u128 },
-    /// Could not compete - bond too low to beat lowest candidate
-    CannotCompete { available: u128, lowest_candidate: u128, needed: u128 },
+    /// Candidate pool is full and our available balance can't clear the bond
+    /// threshold needed to evict the lowest-bonded candidate
+    NotCompetitive { required_bond: u128, available: u128 },
     /// Manual action required (chain doesn't support proxy or is disabled)
     ManualActionRequired { reason: String, current_status: CollatorStatus },
+    /// In the active set but has gone quiet well past the expected per-collator
+    /// authoring cadence (bad session keys, stalled node, etc.)
+    Delinquent { slots_missed: u64, expected_interval_secs: u64 },
+    /// In the active set and still producing *some* blocks (so `Delinquent` above
+    /// hasn't tripped), but the Aura slot-miss rate over the tracking window is so
+    /// high it's effectively not producing - e.g. bad session keys for some but not
+    /// all slots, or a flaky node that's connected but rarely gets to author.
+    SkippingScheduledSlots { authored: u32, expected: u32, window: u32 },
     /// Error occurred during monitoring
     Error(String),
     /// Chain was skipped (not enabled or not valid for network)
     Skipped(String),
+    /// Built and signed a registration/bond-update extrinsic but did not broadcast
+    /// it, because dry-run mode is enabled
+    DryRun { call: String, bond: Option<u128>, signed_payload_hex: String },
+    /// Wrote an offline-signing payload file instead of signing locally, because
+    /// `--sign-only` is enabled - the proxy key never touches this process
+    OfflinePayloadPrepared { path: String, bond: Option<u128> },
 }
 
+/// How many multiples of the expected authoring interval must pass with no
+/// authored block before we consider the collator delinquent.
+const DELINQUENCY_INTERVAL_MULTIPLIER: u64 = 3;
+
+/// How many consecutive delinquency checks must miss before alerting, so a
+/// single unlucky slot doesn't trip a false alarm.
+const DELINQUENCY_MIN_CONSECUTIVE_MISSES: u32 = 2;
+
+/// How many consecutive status-summary intervals an active collator can show zero
+/// reward accrual before we alert that it may be authoring without being rewarded.
+const ZERO_REWARD_ALERT_STREAK: u32 = 3;
+
+/// Minimum reward delta worth notifying about, expressed as a fraction of one whole
+/// token (`10^decimals / REWARD_DUST_THRESHOLD_DIVISOR`). Filters out the kind of
+/// sub-unit noise that free-balance sampling can pick up between real payouts.
+const REWARD_DUST_THRESHOLD_DIVISOR: u128 = 100;
+
+/// How close (in ranks) to the cutoff of a full candidate pool we tolerate before
+/// treating the bond as slipping. Rank 1 is the highest bond; a full pool's cutoff
+/// is rank `max_candidates` - staying within this many slots of it is the target
+/// margin we try to maintain rather than waiting to be evicted outright.
+const BOND_RANK_SAFETY_MARGIN: usize = 2;
+
+/// Minimum margin our bond must hold above the pool's cutoff bond, as a percentage
+/// of the candidacy bond; a bond at or below cutoff plus this margin triggers a
+/// top-up even when our rank is within the safe range.
+const BOND_RANK_MIN_MARGIN_PERCENT: u128 = 5;
+
 /// Monitor and manage collator status across all chains
 pub struct CollatorMonitor {
     config: AppConfig,
-    proxy_signer: subxt_signer::sr25519::Keypair,
+    keystore: Box<dyn Keystore>,
     slack: SlackNotifier,
     block_tracker: Arc<BlockTracker>,
+    /// Live per-collator authorship tracker, fed by `subscribe_finalized()`
+    /// rather than `block_tracker`'s "last block observed on this chain at
+    /// all" tracking - see [`Self::check_delinquency`], the one thing that
+    /// reads from it so far.
+    authorship_tracker: Arc<AuthorshipTracker>,
+    /// Live `CollatorSelection` event subscription, fed by `subscribe_finalized()`
+    /// - lets a `watch` loop react to an eviction/undercut the moment it lands
+    /// rather than waiting for the next poll. Registered the same way as
+    /// `authorship_tracker` (see [`Self::monitor_chain_internal`]); reacted to
+    /// by `run_watch`'s own subscriber via [`Self::collator_event_watcher`].
+    collator_event_watcher: Arc<CollatorEventWatcher>,
+    /// When set, registration builds and signs its extrinsic but never broadcasts it
+    dry_run: bool,
+    /// When set, registration writes an offline-signing payload file instead of
+    /// signing locally at all - for proxy keys that live on an air-gapped machine
+    sign_only: bool,
+    /// When set, slot snapshots and alert counts are published for `/metrics` scraping
+    metrics: Option<Arc<MetricsRegistry>>,
+    /// Last-seen counterpart-chain best-finalized header per BridgeHub chain,
+    /// and how many consecutive cycles it's sat unchanged - the only piece of
+    /// bridge-health state that needs to survive across check cycles (lane
+    /// backlog is derived fresh from a single point-in-time read each cycle).
+    bridge_relay_state: Mutex<HashMap<String, BridgeRelayState>>,
+    /// When set, checked immediately before submitting a new registration or
+    /// bond-update extrinsic so a shutdown in progress doesn't start a
+    /// transaction it can't see through. Not needed by one-shot commands
+    /// (`check`, `prepare`, `broadcast`), only the long-running `watch` loop.
+    shutdown: Option<ShutdownToken>,
+}
+
+/// Tracks whether a BridgeHub's GRANDPA finality relay is making progress
+/// across check cycles - see `CollatorMonitor::bridge_relay_state`.
+#[derive(Debug, Clone, Copy)]
+struct BridgeRelayState {
+    best_finalized: u32,
+    stalled_cycles: u32,
 }
 
 impl CollatorMonitor {
     /// Create a new collator monitor
-    pub fn new(config: AppConfig, block_tracker: Arc<BlockTracker>) -> Result<Self> {
-        // Parse the proxy seed to create a signer
-        let proxy_signer = parse_seed(&config.proxy_seed)
-            .context("Failed to parse proxy seed")?;
-
-        let slack = SlackNotifier::new(
-            config.slack_webhook_url.clone(),
-            config.slack_user_ids.clone(),
-        );
+    pub fn new(config: AppConfig, block_tracker: Arc<BlockTracker>, dry_run: bool, sign_only: bool) -> Result<Self> {
+        let mut keystore = EncryptedFileKeystore::open(&config.keystore_dir, &config.keystore_passphrase)
+            .context("Failed to open encrypted keystore")?;
+
+        // Migrate a legacy plaintext seed into the keystore once, so existing
+        // deployments don't need a separate import step before their first run.
+        if let Some(seed) = &config.proxy_seed {
+            if keystore.get(&config.proxy_key_name).is_err() {
+                import_from_str(&mut keystore, &config.proxy_key_name, seed)
+                    .context("Failed to import legacy proxy seed into keystore")?;
+            }
+        }
+
+        // When a remote signer is configured, or the proxy key lives on an
+        // air-gapped machine entirely (--sign-only), the proxy key is never
+        // imported locally, so there's nothing to check in the keystore.
+        if config.remote_signer_url.is_none() && !sign_only {
+            keystore
+                .get(&config.proxy_key_name)
+                .with_context(|| format!("No key named '{}' in keystore; import one first", config.proxy_key_name))?;
+        }
+
+        if sign_only {
+            config
+                .proxy_account_id
+                .as_deref()
+                .context("--sign-only requires proxy_account_id to be configured")?;
+        }
+
+        let slack = SlackNotifier::from_config(&config);
 
         Ok(Self {
             config,
-            proxy_signer,
+            keystore: Box::new(keystore),
             slack,
             block_tracker,
+            authorship_tracker: Arc::new(AuthorshipTracker::new()),
+            collator_event_watcher: Arc::new(CollatorEventWatcher::new()),
+            dry_run,
+            sign_only,
+            metrics: None,
+            bridge_relay_state: Mutex::new(HashMap::new()),
+            shutdown: None,
         })
     }
 
+    /// Wire up the `/metrics` registry so slot snapshots and alert counts are
+    /// published for scraping, in addition to whatever Slack/Discord/etc.
+    /// channels are configured.
+    pub fn set_metrics(&mut self, metrics: Arc<MetricsRegistry>) {
+        self.slack.set_metrics(metrics.clone());
+        self.metrics = Some(metrics);
+    }
+
+    /// Wire up the shutdown token so the `watch` loop's transaction-submission
+    /// paths refuse to start new work once shutdown has been requested.
+    pub fn set_shutdown_token(&mut self, token: ShutdownToken) {
+        self.shutdown = Some(token);
+    }
+
+    /// Checked immediately before submitting a registration/bond-update
+    /// extrinsic. An already in-flight extrinsic is left alone - this only
+    /// gates the decision to start a *new* one.
+    fn check_not_shutting_down(&self) -> Result<(), CollatorError> {
+        if self.shutdown.as_ref().is_some_and(|s| s.is_requested()) {
+            return Err(CollatorError::Shutdown);
+        }
+        Ok(())
+    }
+
+    /// The configured proxy signer, wrapped behind the [`Signer`] trait so
+    /// registration/bond-update calls don't care whether the key lives
+    /// in-process or behind a remote signer. When `remote_signer_url` is set,
+    /// signing payloads are forwarded there instead of touching the keystore.
+    fn proxy_signer(&self) -> Result<Box<dyn Signer>> {
+        if let Some(url) = &self.config.remote_signer_url {
+            let account_id = self
+                .config
+                .proxy_account_id
+                .as_deref()
+                .context("remote_signer_url is set but proxy_account_id is missing")?
+                .parse::<AccountId32>()
+                .map_err(|e| anyhow::anyhow!("Invalid proxy_account_id: {}", e))?;
+            return Ok(Box::new(RemoteHttpSigner::new(url.clone(), account_id)));
+        }
+
+        let keypair = self.keystore.get(&self.config.proxy_key_name)?;
+        Ok(Box::new(InMemorySigner::new(keypair.clone())))
+    }
+
+    /// The configured proxy account's address, required (but never a local key)
+    /// in `--sign-only` mode.
+    fn proxy_account_id(&self) -> Result<AccountId32> {
+        self.config
+            .proxy_account_id
+            .as_deref()
+            .context("--sign-only requires proxy_account_id to be configured")?
+            .parse::<AccountId32>()
+            .map_err(|e| anyhow::anyhow!("Invalid proxy_account_id: {}", e))
+    }
+
+    /// Where an offline-signing payload for `client`/`bond` gets written under
+    /// `offline_payload_dir`, one file per chain/call kind so concurrent
+    /// sign-only runs across chains don't clobber each other.
+    fn offline_payload_path(&self, client: &ChainClient, bond: Option<u128>) -> std::path::PathBuf {
+        let kind = if bond.is_some() { "bond-update" } else { "register" };
+        let file_name = format!("{}-{}.json", client.chain_name().to_lowercase().replace(' ', "-"), kind);
+        std::path::Path::new(&self.config.offline_payload_dir).join(file_name)
+    }
+
+    /// Write an [`crate::chain_client::OfflinePayload`] out as pretty-printed JSON,
+    /// creating `offline_payload_dir` if it doesn't exist yet.
+    fn write_offline_payload(&self, path: &std::path::Path, payload: &crate::chain_client::OfflinePayload) -> Result<()> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+        }
+        let file = std::fs::File::create(path).with_context(|| format!("Failed to create {}", path.display()))?;
+        serde_json::to_writer_pretty(file, payload).context("Failed to serialize offline payload")?;
+        Ok(())
+    }
+
     /// Get reference to slack notifier (for summary sending)
     pub fn slack(&self) -> &SlackNotifier {
         &self.slack
@@ -91,6 +295,18 @@ impl CollatorMonitor {
         &self.block_tracker
     }
 
+    /// Get the live per-collator authorship tracker - see
+    /// `Self::check_delinquency`.
+    pub fn authorship_tracker(&self) -> &Arc<AuthorshipTracker> {
+        &self.authorship_tracker
+    }
+
+    /// Get reference to the live `CollatorSelection` event subscription (for
+    /// the `watch` loop's reaction task - see [`Self::monitor_chain_internal`]).
+    pub fn collator_event_watcher(&self) -> &Arc<CollatorEventWatcher> {
+        &self.collator_event_watcher
+    }
+
     /// Get the summary interval from config
     pub fn summary_interval_secs(&self) -> u64 {
         self.config.summary_interval_secs
@@ -117,6 +333,22 @@ impl CollatorMonitor {
             SystemChain::Encointer,
         ];
 
+        let westend_chains = [
+            SystemChain::AssetHub,
+            SystemChain::BridgeHub,
+            SystemChain::Collectives,
+            SystemChain::Coretime,
+            SystemChain::People,
+            SystemChain::Glutton,
+        ];
+
+        let paseo_chains = [
+            SystemChain::AssetHub,
+            SystemChain::BridgeHub,
+            SystemChain::Coretime,
+            SystemChain::People,
+        ];
+
         // Monitor Polkadot chains
         for chain in polkadot_chains {
             let result = self.monitor_chain(Network::Polkadot, chain).await;
@@ -129,6 +361,23 @@ impl CollatorMonitor {
             results.push(result);
         }
 
+        // Testnets are opt-in: only monitor them once a collator address is
+        // configured, so deployments that don't care about Westend/Paseo
+        // don't pay for RPC calls to chains they have nothing registered on.
+        if self.config.collator_address(Network::Westend).is_some() {
+            for chain in westend_chains {
+                let result = self.monitor_chain(Network::Westend, chain).await;
+                results.push(result);
+            }
+        }
+
+        if self.config.collator_address(Network::Paseo).is_some() {
+            for chain in paseo_chains {
+                let result = self.monitor_chain(Network::Paseo, chain).await;
+                results.push(result);
+            }
+        }
+
         results
     }
 
@@ -166,7 +415,12 @@ impl CollatorMonitor {
             .unwrap_or_else(|| default_rpc_url(network, chain));
 
         // Get collator address for this network
-        let collator_address = self.config.collator_address(network);
+        let Some(collator_address) = self.config.collator_address(network) else {
+            return MonitorResult {
+                chain_name,
+                status: MonitorStatus::Skipped(format!("No collator address configured for {:?}", network)),
+            };
+        };
 
         info!("Monitoring {} for collator {} (read_only: {})", chain_name, collator_address, read_only);
 
@@ -196,6 +450,13 @@ impl CollatorMonitor {
                 
                 MonitorResult { chain_name, status }
             }
+            Err(e) if matches!(e.downcast_ref::<CollatorError>(), Some(CollatorError::Shutdown)) => {
+                info!("Skipping {}: monitor is shutting down", chain_name);
+                MonitorResult {
+                    chain_name,
+                    status: MonitorStatus::Skipped("Monitor is shutting down".to_string()),
+                }
+            }
             Err(e) => {
                 error!("Error monitoring {}: {}", chain_name, e);
                 let _ = self.slack.notify_error(&chain_name, &e.to_string()).await;
@@ -257,7 +518,7 @@ impl CollatorMonitor {
             .map(|c| c.rpc_url.as_str())
             .unwrap_or_else(|| default_rpc_url(network, chain));
 
-        let collator_address = self.config.collator_address(network);
+        let collator_address = self.config.collator_address(network)?;
 
         let client = match ChainClient::connect(rpc_url, network, chain).await {
             Ok(c) => c,
@@ -309,15 +570,58 @@ impl CollatorMonitor {
             _ => None,
         };
 
-        // Get last authored block time from the block tracker (if we're a collator)
-        let last_block_time = if is_invulnerable || is_candidate {
+        // Get last authored block time/height from the block tracker (if we're a collator)
+        let tracked_block = if is_invulnerable || is_candidate {
             self.block_tracker.get_last_block(&chain_name).await
-                .and_then(|info| info.time_since_last_block())
         } else {
             None
         };
+        let last_block_time = tracked_block.as_ref().and_then(|info| info.time_since_last_block());
+        let last_authored_block = tracked_block.and_then(|info| info.last_authored_block);
+        let current_block = client.get_current_block_number().await.unwrap_or(0);
+
+        let session_snapshot = self.block_tracker.session_snapshot(&chain_name).await;
+        let current_session_index = session_snapshot.map(|(index, _)| index);
+        let blocks_until_next_rotation = session_snapshot.and_then(|(_, started_at_block)| {
+            let session_length = self.config.session_length_blocks(network)?;
+            let blocks_elapsed = current_block.saturating_sub(started_at_block);
+            Some(session_length.saturating_sub(blocks_elapsed))
+        });
+
+        // Approximate the reward pot with the collator's free balance: current claimable
+        // balance already reflects everything paid out and not yet withdrawn further.
+        let (total_rewards_observed, reward_delta_since_last) = if is_invulnerable || is_candidate {
+            let observed_balance = client.get_free_balance(&collator_account).await.unwrap_or(0);
+            let (total, delta) = self.block_tracker.record_reward_observation(&chain_name, observed_balance).await;
+            let dust_threshold = 10u128.pow(network.decimals()) / REWARD_DUST_THRESHOLD_DIVISOR;
+            if delta >= dust_threshold {
+                if let Err(e) = self
+                    .slack
+                    .notify_reward_payout(
+                        &chain_name,
+                        collator_address,
+                        delta,
+                        total,
+                        network.symbol(),
+                        network.decimals(),
+                    )
+                    .await
+                {
+                    warn!("Failed to send reward-payout notification for {}: {}", chain_name, e);
+                }
+            }
+            let streak = self.block_tracker.record_reward_delta(&chain_name, delta).await;
+            if streak >= ZERO_REWARD_ALERT_STREAK {
+                if let Err(e) = self.slack.alert_no_reward_accrual(&chain_name, streak).await {
+                    warn!("Failed to send no-reward-accrual alert for {}: {}", chain_name, e);
+                }
+            }
+            (total, delta)
+        } else {
+            (0, 0)
+        };
 
-        Some(ChainSlotInfo {
+        let slot_info = ChainSlotInfo {
             chain_name,
             is_invulnerable,
             is_candidate,
@@ -328,9 +632,113 @@ impl CollatorMonitor {
             lowest_bond,
             distance_from_last,
             last_block_time,
+            last_authored_block,
+            current_block,
             token_symbol: network.symbol().to_string(),
             decimals: network.decimals(),
-        })
+            total_rewards_observed,
+            reward_delta_since_last,
+            current_session_index,
+            blocks_until_next_rotation,
+        };
+
+        if is_invulnerable || is_candidate {
+            let active_count = client.get_active_collator_count().await.unwrap_or(1).max(1) as u64;
+            let threshold_slots = active_count * DELINQUENCY_INTERVAL_MULTIPLIER;
+            if let Err(e) = self
+                .slack
+                .check_collator_delinquency(&slot_info, collator_address, threshold_slots)
+                .await
+            {
+                warn!("Failed to check collator delinquency for {}: {}", slot_info.chain_name, e);
+            }
+        }
+
+        if let Some(metrics) = &self.metrics {
+            metrics.record_slot(collator_address, &slot_info);
+            metrics.set_outstanding_issues(self.slack.outstanding_issue_count() as u64);
+        }
+
+        Some(slot_info)
+    }
+
+    /// Query `client`'s default bridge message lane and GRANDPA finality
+    /// relay and alert ops if the outbound backlog or relay staleness crosses
+    /// its configured threshold. A no-op (besides logging) for chains
+    /// `get_bridge_lane_health` doesn't have a pallet-name mapping for.
+    /// Errors are logged rather than propagated - bridge health is
+    /// supplementary observability, not something that should fail the
+    /// chain's status check.
+    async fn check_bridge_health(&self, client: &ChainClient) {
+        let health = match client.get_bridge_lane_health().await {
+            Ok(Some(health)) => health,
+            Ok(None) => return,
+            Err(e) => {
+                warn!("Failed to query bridge lane health for {}: {}", client.chain_name(), e);
+                return;
+            }
+        };
+
+        let chain_name = client.chain_name();
+        let backlog_threshold = self.config.bridge_lane_backlog_threshold;
+        if health.outbound_backlog > backlog_threshold {
+            if let Err(e) = self.slack.alert_bridge_lane_backlog(chain_name, health.outbound_backlog, backlog_threshold).await {
+                warn!("Failed to send bridge lane backlog alert for {}: {}", chain_name, e);
+            }
+        } else if let Err(e) = self.slack.clear_bridge_lane_backlog(chain_name).await {
+            warn!("Failed to clear bridge lane backlog alert for {}: {}", chain_name, e);
+        }
+
+        let stall_threshold = self.config.bridge_relay_stall_cycles;
+        let stalled_cycles = {
+            let mut state = self.bridge_relay_state.lock().unwrap();
+            let entry = state.entry(chain_name.to_string()).or_insert(BridgeRelayState {
+                best_finalized: health.counterpart_best_finalized,
+                stalled_cycles: 0,
+            });
+            if health.counterpart_best_finalized > entry.best_finalized {
+                entry.best_finalized = health.counterpart_best_finalized;
+                entry.stalled_cycles = 0;
+            } else {
+                entry.stalled_cycles += 1;
+            }
+            entry.stalled_cycles
+        };
+
+        if stalled_cycles >= stall_threshold {
+            if let Err(e) = self
+                .slack
+                .alert_bridge_relay_stalled(chain_name, stalled_cycles, health.counterpart_best_finalized)
+                .await
+            {
+                warn!("Failed to send bridge relay stalled alert for {}: {}", chain_name, e);
+            }
+        } else if stalled_cycles == 0 {
+            if let Err(e) = self.slack.clear_bridge_relay_stalled(chain_name).await {
+                warn!("Failed to clear bridge relay stalled alert for {}: {}", chain_name, e);
+            }
+        }
+
+        // A GRANDPA-relayed bridge doesn't depend on BEEFY at all, but once a
+        // chain migrates its authority set to BLS12-381, the aggregate key is
+        // what a BEEFY light-client bridge would check a committee signature
+        // against - surface it here too so a future BEEFY-based relay lane
+        // has somewhere to read it from, and so drift in the aggregate is
+        // visible on `/metrics` (`beefy_aggregate_key_changes_total`) rather
+        // than only at `debug!` level.
+        match client.get_beefy_aggregate_bls_key().await {
+            Ok(aggregate) => {
+                if let Some(aggregate) = aggregate {
+                    debug!("{}: BEEFY BLS committee aggregate key {}", chain_name, hex::encode(aggregate));
+                }
+                if let Some(metrics) = &self.metrics {
+                    metrics.record_beefy_aggregate(chain_name, aggregate);
+                }
+            }
+            Err(e) => {
+                debug!("{}: failed to read BEEFY authority set: {}", chain_name, e);
+            }
+        }
     }
 
     async fn monitor_chain_internal(
@@ -339,17 +747,66 @@ impl CollatorMonitor {
         chain: SystemChain,
         rpc_url: &str,
         collator_address: &str,
-        read_only: bool,
+        mut read_only: bool,
     ) -> Result<MonitorStatus> {
-        // Connect to chain
-        let client = ChainClient::connect(rpc_url, network, chain).await?;
+        // Connect to chain - via the embedded light client if this is the
+        // configured light-client target, so its storage reads are checked
+        // against the chain's own header/state proofs instead of trusting
+        // `rpc_url`, otherwise over the plain RPC endpoint as usual.
+        let client = match self.config.light_client_spec_paths(network, chain) {
+            Some((relay_spec_path, chain_spec_path)) => {
+                let relay_chain_spec_json = std::fs::read_to_string(relay_spec_path)
+                    .with_context(|| format!("Failed to read light-client relay chain spec at {}", relay_spec_path))?;
+                let chain_spec_json = std::fs::read_to_string(chain_spec_path)
+                    .with_context(|| format!("Failed to read light-client chain spec at {}", chain_spec_path))?;
+                ChainClient::connect_light(&chain_spec_json, &relay_chain_spec_json, network, chain).await?
+            }
+            None => ChainClient::connect(rpc_url, network, chain).await?,
+        };
 
         // Parse collator address
         let collator_account = client.parse_address(collator_address)?;
 
+        // Idempotent - only spawns `CollatorEventWatcher`'s per-chain watcher
+        // task the first time any account is registered for it.
+        self.collator_event_watcher
+            .watch_account(rpc_url.to_string(), network, chain, collator_account.clone())
+            .await;
+
+        // A signing path must never be attempted against drifted metadata - fall
+        // back to read-only status checks and page whoever can redeploy with
+        // fresh metadata instead of risking a malformed/misinterpreted extrinsic.
+        if let Err(e) = client.check_metadata_drift() {
+            warn!("{}: {}", client.chain_name(), e);
+            let _ = self
+                .slack
+                .alert_manual_action_required(client.chain_name(), &format_account(&collator_account, network), &e.to_string(), None)
+                .await;
+            read_only = true;
+        }
+
+        // BridgeHub doesn't support proxy accounts and is already read-only
+        // status-only - put that otherwise-wasted connection to use surfacing
+        // the bridge health operators actually care about.
+        if chain == SystemChain::BridgeHub {
+            self.check_bridge_health(&client).await;
+        }
+
         // Check current collator status first
         let status = client.get_collator_status(&collator_account).await?;
 
+        // An invulnerable or candidate collator is expected to author roughly once
+        // per full rotation of the active set - check it's actually doing so before
+        // treating "already a collator" as healthy.
+        if status != CollatorStatus::NotCollator {
+            if let Some(delinquent) = self.check_delinquency(&client, &collator_account, rpc_url).await? {
+                return Ok(delinquent);
+            }
+            if let Some(skipping) = self.check_skip_rate(&client).await {
+                return Ok(skipping);
+            }
+        }
+
         // If invulnerable, no action needed - return early
         if status == CollatorStatus::Invulnerable {
             info!(
@@ -363,17 +820,16 @@ impl CollatorMonitor {
         // For candidates and non-collators, we need balance and bond info
         let free_balance = client.get_free_balance(&collator_account).await?;
         let reserve_amount = network.reserve_amount();
-        let available_for_bond = free_balance.saturating_sub(reserve_amount);
+        // Vested/otherwise-locked funds are still "free" but cannot actually be
+        // reserved for a candidacy bond - exclude them from what we'd offer.
+        let locked_balance = client.get_locked_balance(&collator_account).await?;
+        let available_for_bond = free_balance
+            .saturating_sub(reserve_amount)
+            .saturating_sub(locked_balance);
         let candidacy_bond = client.get_candidacy_bond().await?;
         
         // Get current candidates to check competitive bond
         let candidates = client.get_candidates().await?;
-        // Get minimum bond from candidates (only those with bond > 0, sorted ascending)
-        let lowest_candidate_bond = candidates
-            .iter()
-            .filter(|c| c.deposit > 0)
-            .map(|c| c.deposit)
-            .min();
 
         match status.clone() {
             CollatorStatus::Invulnerable => {
@@ -387,7 +843,43 @@ impl CollatorMonitor {
                     client.chain_name(),
                     current_bond
                 );
-                
+
+                // A bonded candidate with no session keys will never author a block -
+                // flag it rather than reporting a silently-healthy status.
+                if !client.has_session_keys(&collator_account).await? {
+                    warn!(
+                        "{} is a candidate on {} but has no session keys set",
+                        collator_address, client.chain_name()
+                    );
+
+                    let _ = self
+                        .slack
+                        .alert_manual_action_required(
+                            client.chain_name(),
+                            &format_account(&collator_account, network),
+                            "Candidate has no session keys set - it will never author a block",
+                            Some(&client.generate_set_keys_call_data()),
+                        )
+                        .await;
+
+                    return Ok(MonitorStatus::ManualActionRequired {
+                        reason: "No session keys set".to_string(),
+                        current_status: status,
+                    });
+                }
+
+                // Before considering a routine top-up, check whether our rank in a
+                // full pool has slipped toward the cutoff - that's worth reacting to
+                // even with an amount too small for the routine threshold below.
+                if !read_only {
+                    if let Some(rebalanced) = self
+                        .maintain_bond_rank(&client, &collator_account, network, current_bond, available_for_bond)
+                        .await?
+                    {
+                        return Ok(rebalanced);
+                    }
+                }
+
                 // When already a candidate, current_bond is LOCKED (not in free_balance)
                 // So the new total bond = current_bond + (free_balance - reserve)
                 let new_total_bond = current_bond.saturating_add(available_for_bond);
@@ -403,10 +895,12 @@ impl CollatorMonitor {
                     format_balance(new_total_bond, network.decimals(), network.symbol()),
                 );
                 
-                // Minimum increase threshold (0.1 DOT or 0.01 KSM) to avoid tiny updates
+                // Minimum increase threshold (0.1 DOT/WND/PAS/ROC or 0.01 KSM) to avoid tiny updates
                 let min_increase = match network {
-                    Network::Polkadot => 1_000_000_000u128, // 0.1 DOT
-                    Network::Kusama => 10_000_000_000u128,  // 0.01 KSM
+                    Network::Polkadot | Network::Westend | Network::Paseo | Network::Rococo => {
+                        10u128.pow(network.decimals() - 1) // 0.1 token
+                    }
+                    Network::Kusama => 10u128.pow(network.decimals() - 2), // 0.01 KSM
                 };
                 
                 // Check if we have meaningful additional funds to bond
@@ -434,7 +928,7 @@ impl CollatorMonitor {
                             .slack
                             .alert_manual_action_required(
                                 client.chain_name(),
-                                &collator_account.to_string(),
+                                &format_account(&collator_account, network),
                                 &format!("Bond can be increased from {} to {}", 
                                     format_balance(current_bond, network.decimals(), network.symbol()),
                                     format_balance(new_total_bond, network.decimals(), network.symbol())),
@@ -448,22 +942,56 @@ impl CollatorMonitor {
                         });
                     }
                     
+                    self.check_not_shutting_down()?;
+
                     info!(
                         "Increasing bond from {} to {} on {}",
                         format_balance(current_bond, network.decimals(), network.symbol()),
                         format_balance(new_total_bond, network.decimals(), network.symbol()),
                         client.chain_name()
                     );
-                    
-                    let tx_hash = client
-                        .update_bond_via_proxy(&collator_account, &self.proxy_signer, new_total_bond)
-                        .await?;
+
+                    let (tx_hash, applied_tip) = match client
+                        .update_bond_via_proxy(
+                            &collator_account,
+                            &self.proxy_signer()?,
+                            new_total_bond,
+                            self.config.tip,
+                            self.config.tip_ceiling,
+                            self.config.resubmit_after_blocks,
+                        )
+                        .await
+                    {
+                        Ok(result) => result,
+                        Err(e) if e.requires_manual_action() => {
+                            warn!(
+                                "{}: bond update requires manual action: {}",
+                                client.chain_name(), e
+                            );
+
+                            let _ = self
+                                .slack
+                                .alert_manual_action_required(
+                                    client.chain_name(),
+                                    &format_account(&collator_account, network),
+                                    &format!("Bond update failed - manual action required: {}", e),
+                                    None,
+                                )
+                                .await;
+
+                            return Ok(MonitorStatus::ManualActionRequired {
+                                reason: e.to_string(),
+                                current_status: status,
+                            });
+                        }
+                        Err(e) => return Err(e.into()),
+                    };
 
                     let _ = self
                         .slack
                         .notify_bond_update(
                             client.chain_name(),
-                            &collator_account.to_string(),
+                            &format_account(&collator_account, network),
                             current_bond,
                             new_total_bond,
                             network.symbol(),
@@ -475,6 +1003,7 @@ impl CollatorMonitor {
                         old_bond: current_bond,
                         new_bond: new_total_bond,
                         tx_hash,
+                        tip: applied_tip,
                     })
                 } else {
                     if available_for_bond > 0 {
@@ -510,9 +1039,10 @@ impl CollatorMonitor {
                         .slack
                         .alert_insufficient_funds(
                             client.chain_name(),
-                            &collator_account.to_string(),
+                            &format_account(&collator_account, network),
                             available_for_bond,
                             candidacy_bond,
+                            locked_balance,
                             network.symbol(),
                             network.decimals(),
                         )
@@ -524,40 +1054,78 @@ impl CollatorMonitor {
                     });
                 }
                 
-                // Second check: can we beat the lowest candidate?
-                // If there are existing candidates, we need to beat the lowest one
-                if let Some(lowest_bond) = lowest_candidate_bond {
-                    if available_for_bond <= lowest_bond {
-                        let needed = lowest_bond.saturating_sub(available_for_bond) + 1;
+                // Second check: is there an open slot, or do we need to evict the
+                // lowest candidate? The pallet only evicts when the candidate list
+                // is actually full (`total_candidates >= desired_candidates`) - when
+                // there's room, any bond meeting `candidacy_bond` is accepted.
+                let pool_status = client.get_candidate_pool_status().await?;
+                let evicted_candidate = if pool_status.has_open_slot() {
+                    None
+                } else {
+                    candidates
+                        .iter()
+                        .filter(|c| c.deposit > 0)
+                        .min_by_key(|c| c.deposit)
+                };
+
+                if let Some(threshold) = pool_status.threshold_bond {
+                    // The set is full - the pallet kicks the strictly-lowest bidder,
+                    // so we must strictly exceed their bond to take the slot.
+                    if available_for_bond <= threshold {
+                        let required_bond = threshold + 1;
                         warn!(
-                            "Cannot compete on {}: available {} <= lowest candidate bond {}. Need {} more.",
+                            "Not competitive on {}: candidate pool full ({}/{}), available {} <= threshold bond {}. Need {} to clear it.",
                             client.chain_name(),
+                            pool_status.total_candidates,
+                            pool_status.max_candidates,
                             available_for_bond,
-                            lowest_bond,
-                            needed
+                            threshold,
+                            required_bond
                         );
 
                         let _ = self
                             .slack
-                            .alert_cannot_compete(
+                            .alert_not_competitive(
                                 client.chain_name(),
-                                &collator_account.to_string(),
+                                &format_account(&collator_account, network),
                                 available_for_bond,
-                                lowest_bond,
-                                needed,
+                                required_bond,
                                 network.symbol(),
                                 network.decimals(),
                             )
                             .await;
 
-                        return Ok(MonitorStatus::CannotCompete {
+                        return Ok(MonitorStatus::NotCompetitive {
                             available: available_for_bond,
-                            lowest_candidate: lowest_bond,
-                            needed,
+                            required_bond,
                         });
                     }
                 }
-                
+
+                // Registering without session keys set produces a bonded candidate that
+                // never authors - refuse to auto-register and ask for keys instead.
+                if !client.has_session_keys(&collator_account).await? {
+                    warn!(
+                        "Refusing to register {} on {}: no session keys set",
+                        collator_address, client.chain_name()
+                    );
+
+                    let _ = self
+                        .slack
+                        .alert_manual_action_required(
+                            client.chain_name(),
+                            &format_account(&collator_account, network),
+                            "No session keys set - set keys before registering as a candidate",
+                            Some(&client.generate_set_keys_call_data()),
+                        )
+                        .await;
+
+                    return Ok(MonitorStatus::ManualActionRequired {
+                        reason: "No session keys set".to_string(),
+                        current_status: status,
+                    });
+                }
+
                 // We can compete! But check if read_only
                 if read_only {
                     let reason = if !chain_supports_proxy(chain) {
@@ -565,7 +1133,7 @@ impl CollatorMonitor {
                     } else {
                         "Chain disabled - registration required".to_string()
                     };
-                    
+
                     warn!(
                         "Manual action needed on {}: registration required",
                         client.chain_name()
@@ -584,7 +1152,7 @@ impl CollatorMonitor {
                         .slack
                         .alert_manual_action_required(
                             client.chain_name(),
-                            &collator_account.to_string(),
+                            &format_account(&collator_account, network),
                             &format!("Registration required with bond {}", 
                                 format_balance(available_for_bond, network.decimals(), network.symbol())),
                             Some(&call_info),
@@ -598,12 +1166,260 @@ impl CollatorMonitor {
                 }
                 
                 // Try to register
-                self.attempt_registration(&client, &collator_account, network, available_for_bond, candidacy_bond)
-                    .await
+                self.attempt_registration(
+                    &client,
+                    &collator_account,
+                    network,
+                    available_for_bond,
+                    candidacy_bond,
+                    evicted_candidate.cloned(),
+                )
+                .await
             }
         }
     }
 
+    /// Check whether an active collator has gone quiet for longer than its expected
+    /// per-collator authoring cadence allows, modeled on the validator delinquent-slot
+    /// distance heuristic: `expected_interval = slot_duration * active_collator_count`.
+    async fn check_delinquency(
+        &self,
+        client: &ChainClient,
+        collator_account: &AccountId32,
+        rpc_url: &str,
+    ) -> Result<Option<MonitorStatus>> {
+        let chain_name = client.chain_name();
+
+        // Idempotent - only spawns `AuthorshipTracker`'s per-chain tracking
+        // task the first time any account is registered for it.
+        self.authorship_tracker
+            .watch_account(rpc_url.to_string(), client.network(), client.chain(), collator_account.clone())
+            .await;
+
+        // Without at least one block already observed by the live tracker
+        // (e.g. a one-off `check` run, or a chain whose tracker is still
+        // priming) we have no authoring history to judge against.
+        let Some(time_since_last) = self.authorship_tracker.get_last_authored_block_time(chain_name, collator_account).await else {
+            return Ok(None);
+        };
+
+        let active_count = client.get_active_collator_count().await?.max(1) as u64;
+        let expected_interval_secs = crate::chain_client::SLOT_DURATION_SECS * active_count;
+        let threshold_secs = expected_interval_secs * DELINQUENCY_INTERVAL_MULTIPLIER;
+
+        let missed_this_check = time_since_last.as_secs() >= threshold_secs;
+        let consecutive_misses = self
+            .block_tracker
+            .record_delinquency_window(chain_name, missed_this_check)
+            .await;
+
+        if missed_this_check && consecutive_misses >= DELINQUENCY_MIN_CONSECUTIVE_MISSES {
+            let slots_missed = time_since_last.as_secs() / expected_interval_secs.max(1);
+
+            warn!(
+                "{}: Delinquent - no block authored in {} slots (expected every ~{}s)",
+                chain_name, slots_missed, expected_interval_secs
+            );
+
+            let _ = self
+                .slack
+                .alert_delinquent(chain_name, slots_missed, expected_interval_secs)
+                .await;
+
+            return Ok(Some(MonitorStatus::Delinquent {
+                slots_missed,
+                expected_interval_secs,
+            }));
+        }
+
+        if !missed_this_check {
+            let _ = self
+                .slack
+                .notify_issue_resolved(chain_name, &format_account(collator_account, client.network()), "Authoring blocks normally")
+                .await;
+        }
+
+        Ok(None)
+    }
+
+    /// Check whether a collator in the active set has a severe Aura slot-miss rate
+    /// over the background tracker's sliding window - still producing *some*
+    /// blocks (so [`Self::check_delinquency`] above hasn't tripped on silence
+    /// alone), but skipping so many of its own scheduled slots it's effectively
+    /// not producing. The window already excludes slots we weren't assigned and
+    /// resets on authority-set-size changes (session rotations), so a collator
+    /// that just re-registered doesn't inherit a stale miss rate.
+    async fn check_skip_rate(&self, client: &ChainClient) -> Option<MonitorStatus> {
+        let chain_name = client.chain_name();
+        let (authored, expected, miss_rate) = self.block_tracker.skip_rate_snapshot(chain_name).await?;
+
+        warn!(
+            "{}: skipping scheduled slots - authored {} of {} expected over the tracking window",
+            chain_name, authored, expected
+        );
+
+        let _ = self
+            .slack
+            .alert_severe_skip_rate(chain_name, authored, expected, miss_rate)
+            .await;
+
+        Some(MonitorStatus::SkippingScheduledSlots {
+            authored,
+            expected,
+            window: crate::block_tracker::SLOT_ACCOUNTING_WINDOW as u32,
+        })
+    }
+
+    /// Check whether a candidate's bond has slipped toward the bottom of a full pool's
+    /// ranking and, if there's newly-available free balance, top it up to regain a
+    /// safety margin over the cutoff. Mirrors ranked-by-stake validator set membership:
+    /// a static bond can silently fall toward eviction as other candidates raise theirs.
+    async fn maintain_bond_rank(
+        &self,
+        client: &ChainClient,
+        collator_account: &AccountId32,
+        network: Network,
+        current_bond: u128,
+        available_for_bond: u128,
+    ) -> Result<Option<MonitorStatus>> {
+        if available_for_bond == 0 {
+            return Ok(None);
+        }
+
+        let pool_status = client.get_candidate_pool_status().await?;
+
+        // An open slot means nobody's getting evicted - there's no rank to defend yet.
+        let Some(threshold_bond) = pool_status.threshold_bond else {
+            return Ok(None);
+        };
+
+        let candidates = client.get_candidates().await?;
+        let mut sorted: Vec<_> = candidates.iter().filter(|c| c.deposit > 0).collect();
+        sorted.sort_by(|a, b| b.deposit.cmp(&a.deposit));
+
+        let Some(rank) = sorted.iter().position(|c| &c.who == collator_account).map(|p| p + 1) else {
+            return Ok(None);
+        };
+
+        let max_candidates = pool_status.max_candidates as usize;
+        let safe_rank = max_candidates.saturating_sub(BOND_RANK_SAFETY_MARGIN).max(1);
+        let candidacy_bond = client.get_candidacy_bond().await?;
+        let min_margin = candidacy_bond * BOND_RANK_MIN_MARGIN_PERCENT / 100;
+
+        let slipping = rank > safe_rank || current_bond <= threshold_bond.saturating_add(min_margin);
+        if !slipping {
+            return Ok(None);
+        }
+
+        let new_bond = current_bond.saturating_add(available_for_bond);
+
+        self.check_not_shutting_down()?;
+
+        info!(
+            "{}: bond rank slipping (rank #{}, safe rank #{}) - topping up bond from {} to {}",
+            client.chain_name(),
+            rank,
+            safe_rank,
+            format_balance(current_bond, network.decimals(), network.symbol()),
+            format_balance(new_bond, network.decimals(), network.symbol()),
+        );
+
+        match client
+            .update_bond_via_proxy(
+                collator_account,
+                &self.proxy_signer()?,
+                new_bond,
+                self.config.tip,
+                self.config.tip_ceiling,
+                self.config.resubmit_after_blocks,
+            )
+            .await
+        {
+            Ok(_) => {
+                let _ = self
+                    .slack
+                    .notify_bond_rebalanced(
+                        client.chain_name(),
+                        &format_account(collator_account, network),
+                        current_bond,
+                        new_bond,
+                        rank,
+                        network.symbol(),
+                        network.decimals(),
+                    )
+                    .await;
+
+                Ok(Some(MonitorStatus::BondRebalanced {
+                    old_bond: current_bond,
+                    new_bond,
+                    rank,
+                }))
+            }
+            Err(e) if e.requires_manual_action() => {
+                warn!("{}: bond rebalance requires manual action: {}", client.chain_name(), e);
+
+                let _ = self
+                    .slack
+                    .alert_manual_action_required(
+                        client.chain_name(),
+                        &format_account(collator_account, network),
+                        &format!("Bond rebalance failed - manual action required: {}", e),
+                        None,
+                    )
+                    .await;
+
+                Ok(Some(MonitorStatus::ManualActionRequired {
+                    reason: e.to_string(),
+                    current_status: CollatorStatus::Candidate { deposit: current_bond },
+                }))
+            }
+            Err(e) => {
+                // Don't fail the whole check - transient/fatal rebalance errors fall back
+                // to the routine top-up logic on the next cycle.
+                warn!("Failed to rebalance bond on {}: {}", client.chain_name(), e);
+                Ok(None)
+            }
+        }
+    }
+
+    /// Translate a typed registration failure into the right `MonitorStatus`,
+    /// escalating to a manual-action alert when the error needs a human (an
+    /// unauthorized proxy, insufficient funds, an expired mortality window or
+    /// outdated metadata) rather than reporting it as just another transient error.
+    async fn handle_registration_error(
+        &self,
+        client: &ChainClient,
+        collator_account: &AccountId32,
+        error: CollatorError,
+    ) -> MonitorStatus {
+        if error.requires_manual_action() {
+            warn!("{}: registration requires manual action: {}", client.chain_name(), error);
+
+            let _ = self
+                .slack
+                .alert_manual_action_required(
+                    client.chain_name(),
+                    &format_account(collator_account, client.network()),
+                    &format!("Registration failed - manual action required: {}", error),
+                    None,
+                )
+                .await;
+
+            return MonitorStatus::ManualActionRequired {
+                reason: error.to_string(),
+                current_status: CollatorStatus::NotCollator,
+            };
+        }
+
+        if error.is_retryable() {
+            warn!("{}: registration failed, will retry next cycle: {}", client.chain_name(), error);
+        } else {
+            error!("{}: registration failed: {}", client.chain_name(), error);
+        }
+
+        MonitorStatus::Error(error.to_string())
+    }
+
     async fn attempt_registration(
         &self,
         client: &ChainClient,
@@ -611,93 +1427,156 @@ impl CollatorMonitor {
         network: Network,
         available_for_bond: u128,
         candidacy_bond: u128,
+        evicted_candidate: Option<crate::chain_client::CandidateInfo>,
     ) -> Result<MonitorStatus> {
         debug!("Candidacy bond: {}", candidacy_bond);
         debug!("Available for bond: {}", available_for_bond);
 
+        if self.sign_only {
+            info!(
+                "Sign-only: writing an offline payload for registration on {} instead of signing locally",
+                client.chain_name()
+            );
+
+            let proxy_account_id = self.proxy_account_id()?;
+            let payload = client
+                .prepare_registration_payload(collator_account, &proxy_account_id)
+                .await?;
+
+            let path = self.offline_payload_path(client, None);
+            self.write_offline_payload(&path, &payload)?;
+
+            return Ok(MonitorStatus::OfflinePayloadPrepared {
+                path: path.display().to_string(),
+                bond: None,
+            });
+        }
+
+        if self.dry_run {
+            info!(
+                "Dry run: building but not submitting registration for {} on {}",
+                collator_account, client.chain_name()
+            );
+
+            let dry_run = client
+                .build_registration_dry_run(collator_account, &self.proxy_signer()?)
+                .await?;
+
+            return Ok(MonitorStatus::DryRun {
+                call: dry_run.call,
+                bond: dry_run.bond,
+                signed_payload_hex: dry_run.signed_payload_hex,
+            });
+        }
+
+        self.check_not_shutting_down()?;
+
         info!(
             "Registering {} as candidate on {} with bond {}",
             collator_account, client.chain_name(), available_for_bond
         );
 
-        // Register as candidate
-        let tx_hash = client
-            .register_as_candidate_via_proxy(collator_account, &self.proxy_signer)
-            .await?;
+        // Register as candidate, evicting the lowest-bonded one if the pool is
+        // full - `take_lowest_slot_via_proxy` decides which of the two the
+        // live pool state actually calls for right before submitting, rather
+        // than trusting the snapshot `evicted_candidate` above was computed
+        // from (which is only used for the post-registration notification).
+        let (tx_hash, tip) = match client
+            .take_lowest_slot_via_proxy(
+                collator_account,
+                &self.proxy_signer()?,
+                available_for_bond,
+                self.config.tip,
+                self.config.tip_ceiling,
+                self.config.resubmit_after_blocks,
+            )
+            .await
+        {
+            Ok(crate::chain_client::LowestSlotOutcome::Registered { tx_hash, tip }) => (tx_hash, tip),
+            Ok(crate::chain_client::LowestSlotOutcome::TookSlot { tx_hash, tip, .. }) => (tx_hash, tip),
+            Err(e) => return Ok(self.handle_registration_error(client, collator_account, e).await),
+        };
 
         // After registration, update the bond to use maximum available funds
-        if available_for_bond > candidacy_bond {
+        if available_for_bond > candidacy_bond && self.check_not_shutting_down().is_err() {
+            // Don't fail the whole operation, registration was successful -
+            // just skip the top-up and let the next check cycle handle it
+            // once shutdown is no longer in progress.
+            info!("Monitor is shutting down - skipping post-registration bond top-up on {}", client.chain_name());
+        } else if available_for_bond > candidacy_bond {
             info!(
                 "Updating bond from {} to {} on {}",
                 candidacy_bond, available_for_bond, client.chain_name()
             );
             match client
-                .update_bond_via_proxy(collator_account, &self.proxy_signer, available_for_bond)
+                .update_bond_via_proxy(
+                    collator_account,
+                    &self.proxy_signer()?,
+                    available_for_bond,
+                    self.config.tip,
+                    self.config.tip_ceiling,
+                    self.config.resubmit_after_blocks,
+                )
                 .await
             {
                 Ok(_) => {
                     info!("Successfully increased bond to maximum");
                 }
                 Err(e) => {
+                    // Don't fail the whole operation, registration was successful - a
+                    // manual-action or retryable bond-top-up failure is caught next cycle.
                     warn!("Failed to increase bond after registration: {}", e);
-                    // Don't fail the whole operation, registration was successful
                 }
             }
         }
 
-        let _ = self
-            .slack
-            .notify_registration_success(
-                client.chain_name(),
-                &collator_account.to_string(),
-                available_for_bond,
-                network.symbol(),
-                network.decimals(),
-            )
-            .await;
-
-        Ok(MonitorStatus::RegisteredAsCandidate {
-            bond: available_for_bond,
-            tx_hash,
-        })
-    }
-}
-
-/// Parse a seed phrase or hex seed into a keypair
-fn parse_seed(seed: &str) -> Result<subxt_signer::sr25519::Keypair> {
-    use subxt_signer::SecretUri;
-    use std::str::FromStr;
-
-    let seed = seed.trim();
+        match evicted_candidate {
+            Some(evicted) => {
+                info!(
+                    "Registered on {} by displacing {} (bond {})",
+                    client.chain_name(), evicted.who, evicted.deposit
+                );
 
-    // Try as mnemonic first (contains spaces)
-    if seed.contains(' ') {
-        // Parse mnemonic using bip39
-        let mnemonic = bip39::Mnemonic::parse(seed)
-            .map_err(|e| anyhow::anyhow!("Invalid mnemonic: {}", e))?;
-        
-        subxt_signer::sr25519::Keypair::from_phrase(&mnemonic, None)
-            .map_err(|e| anyhow::anyhow!("Failed to create keypair from mnemonic: {}", e))
-    } else if seed.starts_with("0x") {
-        // It's a hex seed - convert to secret key bytes
-        let bytes = hex::decode(&seed[2..])
-            .context("Invalid hex seed")?;
-        
-        if bytes.len() != 32 {
-            return Err(anyhow::anyhow!("Hex seed must be 32 bytes, got {}", bytes.len()));
+                let _ = self
+                    .slack
+                    .notify_registration_by_eviction(
+                        client.chain_name(),
+                        &format_account(collator_account, network),
+                        available_for_bond,
+                        &format_account(&evicted.who, network),
+                        evicted.deposit,
+                        network.symbol(),
+                        network.decimals(),
+                    )
+                    .await;
+
+                Ok(MonitorStatus::RegisteredByEviction {
+                    bond: available_for_bond,
+                    evicted: format_account(&evicted.who, network),
+                    evicted_bond: evicted.deposit,
+                    tx_hash,
+                    tip,
+                })
+            }
+            None => {
+                let _ = self
+                    .slack
+                    .notify_registration_success(
+                        client.chain_name(),
+                        &format_account(collator_account, network),
+                        available_for_bond,
+                        network.symbol(),
+                        network.decimals(),
+                    )
+                    .await;
+
+                Ok(MonitorStatus::RegisteredAsCandidate {
+                    bond: available_for_bond,
+                    tx_hash,
+                    tip,
+                })
+            }
         }
-
-        let mut seed_bytes = [0u8; 32];
-        seed_bytes.copy_from_slice(&bytes);
-        
-        subxt_signer::sr25519::Keypair::from_secret_key(seed_bytes)
-            .map_err(|e| anyhow::anyhow!("Invalid seed: {}", e))
-    } else {
-        // Try as URI (e.g., "//Alice" or other derivation paths)
-        let uri = SecretUri::from_str(seed)
-            .map_err(|e| anyhow::anyhow!("Invalid URI format: {}", e))?;
-        
-        subxt_signer::sr25519::Keypair::from_uri(&uri)
-            .map_err(|e| anyhow::anyhow!("Failed to create keypair from URI: {}", e))
     }
 }
+
@@ -0,0 +1,271 @@
+//! Pluggable delivery channels for alerts.
+//!
+//! Historically every alert went straight to Slack. [`NotificationBackend`]
+//! abstracts "deliver this message at this severity" so the same alert can fan
+//! out to several channels at once, each with its own minimum [`Severity`] -
+//! e.g. everything to Slack, but only [`Severity::Critical`] to PagerDuty.
+//! Modeled on [`crate::signer::Signer`]: a plain (non-async-trait) trait
+//! returning a boxed future, so it stays usable as `dyn NotificationBackend`.
+
+use anyhow::Result;
+use serde::Serialize;
+use std::future::Future;
+use std::pin::Pin;
+
+/// How urgent a notification is. Ordered so a backend's minimum severity can
+/// be compared against the severity of a given alert with `>=`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Critical,
+}
+
+impl Severity {
+    fn label(&self) -> &'static str {
+        match self {
+            Severity::Info => "INFO",
+            Severity::Warning => "WARNING",
+            Severity::Critical => "CRITICAL",
+        }
+    }
+}
+
+/// Delivers a message to one external channel. A failed delivery must return
+/// `Err` rather than panic - the caller fans out to every configured backend
+/// and logs individual failures without letting one backend abort the rest.
+pub trait NotificationBackend: Send + Sync {
+    fn deliver<'a>(
+        &'a self,
+        severity: Severity,
+        message: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>>;
+}
+
+/// Posts to a Discord incoming webhook.
+pub struct DiscordBackend {
+    webhook_url: String,
+    client: reqwest::Client,
+}
+
+impl DiscordBackend {
+    pub fn new(webhook_url: String) -> Self {
+        Self {
+            webhook_url,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct DiscordMessage {
+    content: String,
+}
+
+impl NotificationBackend for DiscordBackend {
+    fn deliver<'a>(
+        &'a self,
+        severity: Severity,
+        message: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            let payload = DiscordMessage {
+                content: format!("**[{}]** {}", severity.label(), message),
+            };
+
+            let response = self.client.post(&self.webhook_url).json(&payload).send().await?;
+            if response.status().is_success() {
+                return Ok(());
+            }
+
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            Err(anyhow::anyhow!("Discord webhook failed: {} - {}", status, body))
+        })
+    }
+}
+
+/// Sends via the Telegram bot API (`sendMessage`).
+pub struct TelegramBackend {
+    bot_token: String,
+    chat_id: String,
+    client: reqwest::Client,
+}
+
+impl TelegramBackend {
+    pub fn new(bot_token: String, chat_id: String) -> Self {
+        Self {
+            bot_token,
+            chat_id,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct TelegramMessage {
+    chat_id: String,
+    text: String,
+}
+
+impl NotificationBackend for TelegramBackend {
+    fn deliver<'a>(
+        &'a self,
+        severity: Severity,
+        message: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            let url = format!("https://api.telegram.org/bot{}/sendMessage", self.bot_token);
+            let payload = TelegramMessage {
+                chat_id: self.chat_id.clone(),
+                text: format!("[{}] {}", severity.label(), message),
+            };
+
+            let response = self.client.post(&url).json(&payload).send().await?;
+            if response.status().is_success() {
+                return Ok(());
+            }
+
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            Err(anyhow::anyhow!("Telegram sendMessage failed: {} - {}", status, body))
+        })
+    }
+}
+
+/// Posts a generic JSON payload to an arbitrary webhook endpoint. When
+/// `signing_secret` is set, the body is signed with HMAC-SHA256 and attached
+/// as `X-Signature` (hex-encoded) so the receiver can verify authenticity -
+/// the same shape PagerDuty/GitHub-style webhook consumers expect.
+pub struct WebhookBackend {
+    url: String,
+    signing_secret: Option<String>,
+    client: reqwest::Client,
+}
+
+impl WebhookBackend {
+    pub fn new(url: String, signing_secret: Option<String>) -> Self {
+        Self {
+            url,
+            signing_secret,
+            client: reqwest::Client::new(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct WebhookPayload<'a> {
+    severity: &'a str,
+    message: &'a str,
+}
+
+impl NotificationBackend for WebhookBackend {
+    fn deliver<'a>(
+        &'a self,
+        severity: Severity,
+        message: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            let payload = WebhookPayload {
+                severity: severity.label(),
+                message,
+            };
+            let body = serde_json::to_vec(&payload)?;
+
+            let mut request = self.client.post(&self.url).header("Content-Type", "application/json");
+            if let Some(secret) = &self.signing_secret {
+                use hmac::{Hmac, Mac};
+                let mut mac = Hmac::<sha2::Sha256>::new_from_slice(secret.as_bytes())
+                    .expect("HMAC accepts a key of any length");
+                mac.update(&body);
+                let signature = hex::encode(mac.finalize().into_bytes());
+                request = request.header("X-Signature", signature);
+            }
+
+            let response = request.body(body).send().await?;
+            if response.status().is_success() {
+                return Ok(());
+            }
+
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            Err(anyhow::anyhow!("Webhook delivery failed: {} - {}", status, text))
+        })
+    }
+}
+
+/// Triggers a PagerDuty Events API v2 incident. Intended to be routed at a
+/// high minimum severity (see `PagerDutyBackend`'s construction in
+/// [`crate::slack::SlackNotifier`]) so only real incidents page someone.
+pub struct PagerDutyBackend {
+    routing_key: String,
+    client: reqwest::Client,
+}
+
+impl PagerDutyBackend {
+    pub fn new(routing_key: String) -> Self {
+        Self {
+            routing_key,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// Map our severity onto the PagerDuty Events API v2 severity enum
+    /// (`critical` | `error` | `warning` | `info`).
+    fn pagerduty_severity(severity: Severity) -> &'static str {
+        match severity {
+            Severity::Critical => "critical",
+            Severity::Warning => "warning",
+            Severity::Info => "info",
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct PagerDutyPayload<'a> {
+    summary: &'a str,
+    source: &'a str,
+    severity: &'a str,
+}
+
+#[derive(Serialize)]
+struct PagerDutyEvent<'a> {
+    routing_key: &'a str,
+    event_action: &'a str,
+    payload: PagerDutyPayload<'a>,
+}
+
+impl NotificationBackend for PagerDutyBackend {
+    fn deliver<'a>(
+        &'a self,
+        severity: Severity,
+        message: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            let event = PagerDutyEvent {
+                routing_key: &self.routing_key,
+                event_action: "trigger",
+                payload: PagerDutyPayload {
+                    summary: message,
+                    source: "collator-monitor",
+                    severity: Self::pagerduty_severity(severity),
+                },
+            };
+
+            let response = self
+                .client
+                .post("https://events.pagerduty.com/v2/enqueue")
+                .json(&event)
+                .send()
+                .await?;
+
+            if response.status().is_success() {
+                return Ok(());
+            }
+
+            let status = response.status();
+            let body = response.text().await.unwrap_or_default();
+            Err(anyhow::anyhow!("PagerDuty event failed: {} - {}", status, body))
+        })
+    }
+}
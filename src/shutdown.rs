@@ -0,0 +1,77 @@
+//! Coordinated shutdown for the monitor's long-lived run loops.
+//!
+//! SIGINT/SIGTERM (see [`wait_for_shutdown_signal`]) flips a `watch::Receiver<bool>`
+//! cancellation token threaded through `run_watch`'s check/summary loop and
+//! [`CollatorMonitor`](crate::monitor::CollatorMonitor)'s transaction-submission
+//! paths: a handler checks the token immediately before submitting a new
+//! registration/bond-update extrinsic, so nothing new gets broadcast once
+//! shutdown is underway, while anything already submitted is left to reach
+//! finalization on its own rather than aborted mid-flight.
+
+use tokio::sync::watch;
+use tracing::info;
+
+/// A cancellation token for coordinated shutdown - cheap to clone, and
+/// [`Self::is_requested`] is a plain synchronous check safe to call right
+/// before a decision point (e.g. "submit this transaction or not") without
+/// awaiting anything.
+#[derive(Clone)]
+pub struct ShutdownToken {
+    rx: watch::Receiver<bool>,
+}
+
+impl ShutdownToken {
+    /// Whether shutdown has been requested.
+    pub fn is_requested(&self) -> bool {
+        *self.rx.borrow()
+    }
+
+    /// Resolves once shutdown is requested - for a `tokio::select!` arm in a
+    /// run loop that should stop sleeping and exit as soon as it's signalled.
+    pub async fn wait(&mut self) {
+        let _ = self.rx.changed().await;
+    }
+}
+
+/// Install SIGINT/SIGTERM handlers and return a token run loops can poll or
+/// wait on. [`BlockTracker::start_tracking`](crate::block_tracker::BlockTracker::start_tracking)
+/// takes a clone of this same token to drive its own internal shutdown
+/// signal for the background chain trackers, so the process only ever
+/// installs one OS-level signal listener no matter how many subsystems
+/// need to react to it.
+pub fn install() -> ShutdownToken {
+    let (tx, rx) = watch::channel(false);
+
+    tokio::spawn(async move {
+        wait_for_shutdown_signal().await;
+        info!("Shutdown signal received");
+        let _ = tx.send(true);
+    });
+
+    ShutdownToken { rx }
+}
+
+/// Wait for a process termination signal: SIGINT (Ctrl+C) everywhere, plus
+/// SIGTERM on unix so an orchestrator (systemd, Kubernetes) can request a
+/// graceful stop the same way a terminal user would.
+async fn wait_for_shutdown_signal() {
+    #[cfg(unix)]
+    {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut sigterm) => {
+                tokio::select! {
+                    _ = tokio::signal::ctrl_c() => {}
+                    _ = sigterm.recv() => {}
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to install SIGTERM handler, falling back to SIGINT only: {}", e);
+                let _ = tokio::signal::ctrl_c().await;
+            }
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = tokio::signal::ctrl_c().await;
+    }
+}
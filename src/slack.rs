@@ -6,10 +6,17 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
-use std::sync::Mutex;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 use tracing::{info, warn, debug};
 
+use crate::notification_backend::{
+    DiscordBackend, NotificationBackend, PagerDutyBackend, Severity, TelegramBackend, WebhookBackend,
+};
+
 /// Slack message payload for posting
 #[derive(Serialize)]
 struct SlackPostMessage {
@@ -71,8 +78,27 @@ struct SlackResponse {
     error: Option<String>,
 }
 
-/// Rate limit configuration
-const RATE_LIMIT_DURATION: Duration = Duration::from_secs(4 * 60 * 60); // 4 hours
+/// Suppression window schedule for a repeated alert under the same rate key:
+/// the first occurrence always fires immediately, then each further occurrence
+/// with an unchanged payload waits progressively longer, capping at the final
+/// entry. A payload change (e.g. a growing shortfall, or severity rising)
+/// bypasses the window entirely and re-alerts right away.
+const BACKOFF_SCHEDULE: &[Duration] = &[
+    Duration::from_secs(15 * 60),
+    Duration::from_secs(30 * 60),
+    Duration::from_secs(60 * 60),
+    Duration::from_secs(2 * 60 * 60),
+    Duration::from_secs(4 * 60 * 60),
+];
+
+/// Multiple of the slots-since-authored threshold at which a block-height
+/// delinquency alert escalates from WARN to CRITICAL.
+const DELINQUENCY_CRITICAL_MULTIPLIER: u64 = 3;
+
+/// How long a collator is given after (re)joining the active set before it's
+/// eligible for a block-height delinquency alert, so a fresh registration or
+/// eviction recovery isn't immediately flagged as delinquent.
+const DELINQUENCY_GRACE_PERIOD: Duration = Duration::from_secs(30 * 60);
 
 /// Information about a chain's collator slot status
 #[derive(Debug, Clone)]
@@ -87,8 +113,21 @@ pub struct ChainSlotInfo {
     pub lowest_bond: Option<u128>,
     pub distance_from_last: Option<u128>,
     pub last_block_time: Option<std::time::Duration>,
+    /// Height of the last block authored by this collator (None if never seen).
+    pub last_authored_block: Option<u64>,
+    /// Latest known block height, for computing `slots_since_authored`.
+    pub current_block: u64,
     pub token_symbol: String,
     pub decimals: u32,
+    pub total_rewards_observed: u128,
+    pub reward_delta_since_last: u128,
+    /// Current `pallet_session` session index, if a rotation has been observed
+    /// since tracking began (see `BlockTracker::session_snapshot`).
+    pub current_session_index: Option<u32>,
+    /// Estimated blocks remaining until the next session rotation, derived
+    /// from the configured session-length estimate - `None` if no estimate is
+    /// configured or no rotation has been observed yet.
+    pub blocks_until_next_rotation: Option<u64>,
 }
 
 /// Reference to a posted Slack message (for updates/deletes)
@@ -106,6 +145,16 @@ struct TrackedAlert {
     started_at: Instant,
 }
 
+/// Per-chain state for block-height-based delinquency detection: the last
+/// authored block height we've observed and whether the collator was in the
+/// active set as of the previous check (to detect "just joined").
+#[derive(Debug)]
+struct DelinquencyTracking {
+    last_authored_block: Option<u64>,
+    was_active: bool,
+    active_since: Instant,
+}
+
 /// Helper for async delete operations (to avoid cloning full SlackNotifier)
 #[derive(Clone)]
 struct DeleteHelper {
@@ -137,7 +186,115 @@ impl DeleteHelper {
     }
 }
 
-/// Slack notifier for sending alerts
+/// Delivers to Slack via bot token (preferred) or webhook. This is the
+/// [`NotificationBackend`] wrapping of Slack so it fans out alongside
+/// Discord/Telegram/PagerDuty/generic-webhook backends, sharing the same
+/// payload shapes the message-ref-tracking methods below use.
+struct SlackBackend {
+    webhook_url: Option<String>,
+    bot_token: Option<String>,
+    channel: Option<String>,
+    client: reqwest::Client,
+}
+
+impl NotificationBackend for SlackBackend {
+    fn deliver<'a>(
+        &'a self,
+        _severity: Severity,
+        message: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<()>> + Send + 'a>> {
+        Box::pin(async move {
+            if let (Some(token), Some(channel)) = (&self.bot_token, &self.channel) {
+                let payload = SlackPostMessage {
+                    channel: channel.clone(),
+                    text: message.to_string(),
+                    blocks: Some(vec![SlackBlock {
+                        block_type: "section".to_string(),
+                        text: Some(SlackText {
+                            text_type: "mrkdwn".to_string(),
+                            text: message.to_string(),
+                        }),
+                    }]),
+                };
+
+                let response = self
+                    .client
+                    .post("https://slack.com/api/chat.postMessage")
+                    .bearer_auth(token)
+                    .json(&payload)
+                    .send()
+                    .await?;
+
+                if response.status().is_success() {
+                    return Ok(());
+                }
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(anyhow::anyhow!("Slack notification failed: {} - {}", status, body));
+            }
+
+            if let Some(webhook_url) = &self.webhook_url {
+                let payload = SlackWebhookMessage {
+                    text: message.to_string(),
+                    blocks: Some(vec![SlackBlock {
+                        block_type: "section".to_string(),
+                        text: Some(SlackText {
+                            text_type: "mrkdwn".to_string(),
+                            text: message.to_string(),
+                        }),
+                    }]),
+                };
+
+                let response = self.client.post(webhook_url).json(&payload).send().await?;
+                if response.status().is_success() {
+                    return Ok(());
+                }
+                let status = response.status();
+                let body = response.text().await.unwrap_or_default();
+                return Err(anyhow::anyhow!("Slack notification failed: {} - {}", status, body));
+            }
+
+            info!("Slack not configured, skipping notification");
+            info!("Message would have been: {}", message);
+            Ok(())
+        })
+    }
+}
+
+/// A configured delivery channel plus the minimum [`Severity`] it should
+/// receive - e.g. a PagerDuty backend routed at `Severity::Critical` only
+/// pages on real incidents, while Slack is routed at `Severity::Info` to get
+/// everything.
+struct RoutedBackend {
+    min_severity: Severity,
+    backend: Box<dyn NotificationBackend>,
+}
+
+/// Escalation state for one rate-limited alert key: when it first appeared,
+/// when it last actually fired, how many times it's been checked since, and a
+/// hash of its last quantitative payload so a worsening situation can bypass
+/// the backoff window rather than wait it out.
+struct RateLimitState {
+    first_seen: Instant,
+    last_notified: Instant,
+    occurrence_count: u32,
+    last_payload_hash: u64,
+}
+
+/// What the caller needs to render an escalated alert: how many times it's
+/// recurred and how long it's been ongoing since first seen.
+struct AlertOccurrence {
+    occurrence_count: u32,
+    ongoing_for: Duration,
+}
+
+/// Alerting service. Despite the name (kept for the sake of every existing
+/// call site), it's no longer Slack-only: every `send`/`send_with_severity`
+/// call fans out across `backends`, which always includes Slack and can be
+/// extended with Discord/Telegram/PagerDuty/generic-webhook via
+/// [`Self::add_backend`]. The message-ref-tracking methods (disconnect/block
+/// alerts that get updated then deleted) remain Slack-specific, since that
+/// capability doesn't generalize to the other channels.
 pub struct SlackNotifier {
     /// Webhook URL (for simple posting only)
     webhook_url: Option<String>,
@@ -151,8 +308,10 @@ pub struct SlackNotifier {
     user_ids_ops: Vec<String>,
     /// HTTP client
     client: reqwest::Client,
-    /// Track last notification time per chain for rate limiting
-    last_notification: Mutex<HashMap<String, Instant>>,
+    /// Every configured delivery channel, each gated by its own minimum severity.
+    backends: Vec<RoutedBackend>,
+    /// Per rate-key escalation/backoff state (see [`Self::should_notify`])
+    rate_limit_state: Mutex<HashMap<String, RateLimitState>>,
     /// Track chains with outstanding issues
     outstanding_issues: Mutex<HashSet<String>>,
     /// Track chains that had manual action required
@@ -161,6 +320,10 @@ pub struct SlackNotifier {
     disconnect_alerts: Mutex<HashMap<String, TrackedAlert>>,
     /// Track block production alerts by chain name
     block_alerts: Mutex<HashMap<String, TrackedAlert>>,
+    /// Track per-chain block-height delinquency state (see [`check_collator_delinquency`])
+    delinquency_tracking: Mutex<HashMap<String, DelinquencyTracking>>,
+    /// When set, every alert also increments `alerts_sent_total{type=...}` for `/metrics` scraping
+    metrics: Option<Arc<crate::metrics::MetricsRegistry>>,
 }
 
 impl SlackNotifier {
@@ -169,35 +332,140 @@ impl SlackNotifier {
     /// For full functionality (update/delete messages), provide bot_token and channel.
     /// Webhook URL can still be used for simple notifications.
     pub fn new(webhook_url: Option<String>, user_ids_onchain: Vec<String>, user_ids_ops: Vec<String>) -> Self {
+        let client = reqwest::Client::new();
+        let backends = vec![RoutedBackend {
+            min_severity: Severity::Info,
+            backend: Box::new(SlackBackend {
+                webhook_url: webhook_url.clone(),
+                bot_token: None,
+                channel: None,
+                client: client.clone(),
+            }),
+        }];
+
         Self {
             webhook_url,
             bot_token: None,
             channel: None,
             user_ids_onchain,
             user_ids_ops,
-            client: reqwest::Client::new(),
-            last_notification: Mutex::new(HashMap::new()),
+            client,
+            backends,
+            rate_limit_state: Mutex::new(HashMap::new()),
             outstanding_issues: Mutex::new(HashSet::new()),
             manual_action_chains: Mutex::new(HashSet::new()),
             disconnect_alerts: Mutex::new(HashMap::new()),
             block_alerts: Mutex::new(HashMap::new()),
+            delinquency_tracking: Mutex::new(HashMap::new()),
+            metrics: None,
         }
     }
 
     /// Create with bot token for full API access
     pub fn with_bot_token(bot_token: String, channel: String, user_ids_onchain: Vec<String>, user_ids_ops: Vec<String>) -> Self {
+        let client = reqwest::Client::new();
+        let backends = vec![RoutedBackend {
+            min_severity: Severity::Info,
+            backend: Box::new(SlackBackend {
+                webhook_url: None,
+                bot_token: Some(bot_token.clone()),
+                channel: Some(channel.clone()),
+                client: client.clone(),
+            }),
+        }];
+
         Self {
             webhook_url: None,
             bot_token: Some(bot_token),
             channel: Some(channel),
             user_ids_onchain,
             user_ids_ops,
-            client: reqwest::Client::new(),
-            last_notification: Mutex::new(HashMap::new()),
+            client,
+            backends,
+            rate_limit_state: Mutex::new(HashMap::new()),
             outstanding_issues: Mutex::new(HashSet::new()),
             manual_action_chains: Mutex::new(HashSet::new()),
             disconnect_alerts: Mutex::new(HashMap::new()),
             block_alerts: Mutex::new(HashMap::new()),
+            delinquency_tracking: Mutex::new(HashMap::new()),
+            metrics: None,
+        }
+    }
+
+    /// Add another delivery channel, gated by its own minimum severity (e.g.
+    /// route PagerDuty at `Severity::Critical` so it only pages on real
+    /// incidents, while Slack stays at `Severity::Info` to get everything).
+    pub fn add_backend(&mut self, min_severity: Severity, backend: Box<dyn NotificationBackend>) {
+        self.backends.push(RoutedBackend { min_severity, backend });
+    }
+
+    /// Build a notifier from config, preferring the Slack bot token (full
+    /// update/delete support) over the webhook, then registering every other
+    /// configured channel (Discord/Telegram/generic webhook/PagerDuty).
+    /// PagerDuty is routed at `Severity::Critical` only; everything else gets
+    /// every severity.
+    pub fn from_config(config: &crate::config::AppConfig) -> Self {
+        let mut notifier = if let (Some(bot_token), Some(channel)) =
+            (&config.slack_bot_token, &config.slack_channel)
+        {
+            Self::with_bot_token(
+                bot_token.clone(),
+                channel.clone(),
+                config.slack_user_ids_onchain.clone(),
+                config.slack_user_ids_ops.clone(),
+            )
+        } else {
+            Self::new(
+                config.slack_webhook_url.clone(),
+                config.slack_user_ids_onchain.clone(),
+                config.slack_user_ids_ops.clone(),
+            )
+        };
+
+        if let Some(url) = &config.discord_webhook_url {
+            notifier.add_backend(Severity::Info, Box::new(DiscordBackend::new(url.clone())));
+        }
+
+        if let (Some(bot_token), Some(chat_id)) = (&config.telegram_bot_token, &config.telegram_chat_id) {
+            notifier.add_backend(
+                Severity::Info,
+                Box::new(TelegramBackend::new(bot_token.clone(), chat_id.clone())),
+            );
+        }
+
+        if let Some(url) = &config.webhook_url {
+            notifier.add_backend(
+                Severity::Info,
+                Box::new(WebhookBackend::new(url.clone(), config.webhook_signing_secret.clone())),
+            );
+        }
+
+        if let Some(routing_key) = &config.pagerduty_routing_key {
+            notifier.add_backend(
+                Severity::Critical,
+                Box::new(PagerDutyBackend::new(routing_key.clone())),
+            );
+        }
+
+        notifier
+    }
+
+    /// Wire up the `/metrics` registry so every alert also increments
+    /// `alerts_sent_total{type=...}` for scraping.
+    pub fn set_metrics(&mut self, metrics: Arc<crate::metrics::MetricsRegistry>) {
+        self.metrics = Some(metrics);
+    }
+
+    /// Number of chains currently tracked as having an unresolved alert, for
+    /// the `outstanding_issues` metric.
+    pub fn outstanding_issue_count(&self) -> usize {
+        self.outstanding_issues.lock().unwrap().len()
+    }
+
+    /// Increment `alerts_sent_total{type="<alert_type>"}` if a metrics registry is configured.
+    fn record_alert(&self, alert_type: &str) {
+        if let Some(metrics) = &self.metrics {
+            metrics.incr_alert(alert_type);
         }
     }
 
@@ -564,7 +832,7 @@ impl SlackNotifier {
     pub async fn send_alert(&self, message: &str) -> Result<()> {
         let mentions = self.format_ops_mentions();
         let full_message = format!("{}{}", message, mentions);
-        self.send(&full_message).await
+        self.send_with_severity(Severity::Critical, &full_message).await
     }
 
     /// Add a chain to outstanding issues
@@ -577,6 +845,7 @@ impl SlackNotifier {
     pub fn remove_outstanding_issue(&self, chain_name: &str) {
         let mut issues = self.outstanding_issues.lock().unwrap();
         issues.remove(chain_name);
+        self.clear_rate_limit_state(chain_name);
     }
 
     /// Check if a chain has an outstanding issue
@@ -601,85 +870,90 @@ impl SlackNotifier {
     pub fn clear_manual_action_required(&self, chain_name: &str) {
         let mut chains = self.manual_action_chains.lock().unwrap();
         chains.remove(chain_name);
+        self.clear_rate_limit_state(chain_name);
     }
 
-    /// Check if we should send a notification (rate limiting)
-    fn should_notify(&self, key: &str) -> bool {
-        let mut last = self.last_notification.lock().unwrap();
-        if let Some(last_time) = last.get(key) {
-            if last_time.elapsed() < RATE_LIMIT_DURATION {
-                return false;
-            }
-        }
-        last.insert(key.to_string(), Instant::now());
-        true
+    /// Drop all backoff state for `chain_name` so a recurrence of any alert on
+    /// it starts fresh rather than inheriting the old escalation. Rate keys are
+    /// always formatted as `"{chain_name}:{alert_kind}"`, so a prefix match
+    /// catches every alert kind for this chain at once.
+    fn clear_rate_limit_state(&self, chain_name: &str) {
+        let prefix = format!("{}:", chain_name);
+        self.rate_limit_state.lock().unwrap().retain(|key, _| !key.starts_with(&prefix));
     }
 
-    /// Send a notification to Slack
-    async fn send(&self, message: &str) -> Result<()> {
-        // Try bot token first
-        if let (Some(token), Some(channel)) = (&self.bot_token, &self.channel) {
-            let payload = SlackPostMessage {
-                channel: channel.clone(),
-                text: message.to_string(),
-                blocks: Some(vec![SlackBlock {
-                    block_type: "section".to_string(),
-                    text: Some(SlackText {
-                        text_type: "mrkdwn".to_string(),
-                        text: message.to_string(),
-                    }),
-                }]),
-            };
+    /// Check whether a repeated alert under `key` should fire now, given a hash
+    /// of its current quantitative payload (see [`Self::payload_hash`]).
+    /// The first occurrence always fires; after that the suppression window
+    /// grows per [`BACKOFF_SCHEDULE`] as long as the payload stays the same,
+    /// but a changed payload (a worsening shortfall, a rising severity tier)
+    /// bypasses the window and fires immediately. Returns the running
+    /// occurrence count and elapsed time since first seen so the caller can
+    /// report both in the message.
+    fn should_notify(&self, key: &str, payload_hash: u64) -> Option<AlertOccurrence> {
+        let mut state = self.rate_limit_state.lock().unwrap();
+        let now = Instant::now();
 
-            let response = self.client
-                .post("https://slack.com/api/chat.postMessage")
-                .bearer_auth(token)
-                .json(&payload)
-                .send()
-                .await?;
+        let tracked = state.entry(key.to_string()).or_insert_with(|| RateLimitState {
+            first_seen: now,
+            last_notified: now,
+            occurrence_count: 0,
+            last_payload_hash: payload_hash,
+        });
 
-            if response.status().is_success() {
-                info!("Slack notification sent successfully");
-                return Ok(());
-            } else {
-                let status = response.status();
-                let body = response.text().await.unwrap_or_default();
-                warn!("Failed to send Slack notification: {} - {}", status, body);
+        let is_first_occurrence = tracked.occurrence_count == 0;
+        let payload_changed = tracked.last_payload_hash != payload_hash;
+        tracked.occurrence_count += 1;
+        tracked.last_payload_hash = payload_hash;
+
+        if !is_first_occurrence && !payload_changed {
+            let window_index = (tracked.occurrence_count as usize - 1).min(BACKOFF_SCHEDULE.len()) - 1;
+            let window = BACKOFF_SCHEDULE[window_index];
+            if tracked.last_notified.elapsed() < window {
+                return None;
             }
         }
-        
-        // Fall back to webhook
-        if let Some(webhook_url) = &self.webhook_url {
-            let payload = SlackWebhookMessage {
-                text: message.to_string(),
-                blocks: Some(vec![SlackBlock {
-                    block_type: "section".to_string(),
-                    text: Some(SlackText {
-                        text_type: "mrkdwn".to_string(),
-                        text: message.to_string(),
-                    }),
-                }]),
-            };
 
-            let response = self.client
-                .post(webhook_url)
-                .json(&payload)
-                .send()
-                .await?;
+        tracked.last_notified = now;
+        Some(AlertOccurrence {
+            occurrence_count: tracked.occurrence_count,
+            ongoing_for: tracked.first_seen.elapsed(),
+        })
+    }
 
-            if response.status().is_success() {
-                info!("Slack notification sent successfully (webhook)");
-                return Ok(());
-            } else {
-                let status = response.status();
-                let body = response.text().await.unwrap_or_default();
-                warn!("Failed to send Slack notification: {} - {}", status, body);
-                return Err(anyhow::anyhow!("Slack notification failed: {} - {}", status, body));
+    /// Hash a few quantitative fields of an alert's payload, so
+    /// [`Self::should_notify`] can detect a worsening situation (a growing
+    /// shortfall, a rising severity tier) and bypass the backoff window.
+    fn payload_hash(fields: &[&dyn std::fmt::Display]) -> u64 {
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        for field in fields {
+            field.to_string().hash(&mut hasher);
+        }
+        hasher.finish()
+    }
+
+
+    /// Send a notification at `Severity::Info` to every configured channel.
+    /// Most alerts are informational; the few that aren't call
+    /// [`Self::send_with_severity`] directly with the right tier.
+    async fn send(&self, message: &str) -> Result<()> {
+        self.send_with_severity(Severity::Info, message).await
+    }
+
+    /// Fan `message` out to every backend whose minimum severity `severity`
+    /// meets, logging (but not propagating) individual backend failures so
+    /// one broken channel never stops delivery to the rest.
+    async fn send_with_severity(&self, severity: Severity, message: &str) -> Result<()> {
+        for routed in &self.backends {
+            if severity < routed.min_severity {
+                continue;
+            }
+
+            if let Err(e) = routed.backend.deliver(severity, message).await {
+                warn!("Notification backend failed to deliver: {}", e);
             }
         }
 
-        info!("Slack not configured, skipping notification");
-        info!("Message would have been: {}", message);
         Ok(())
     }
 
@@ -695,32 +969,46 @@ impl SlackNotifier {
         collator_address: &str,
         available_balance: u128,
         required_balance: u128,
+        locked_balance: u128,
         token_symbol: &str,
         decimals: u32,
     ) -> Result<()> {
         self.add_outstanding_issue(chain_name);
 
         let rate_key = format!("{}:insufficient_funds", chain_name);
-        if !self.should_notify(&rate_key) {
+        let payload_hash = Self::payload_hash(&[&available_balance, &required_balance, &locked_balance]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
             info!("Rate limited: insufficient funds alert for {}", chain_name);
             return Ok(());
-        }
+        };
 
         let available = format_balance(available_balance, decimals, token_symbol);
         let required = format_balance(required_balance, decimals, token_symbol);
         let mentions = self.format_onchain_mentions();
 
+        let locked_line = if locked_balance > 0 {
+            format!(
+                "\nLocked (vesting/other): {}",
+                format_balance(locked_balance, decimals, token_symbol)
+            )
+        } else {
+            String::new()
+        };
+
         let message = format!(
             "‚ö†Ô∏è *Insufficient funds* on *{}*\n\n\
             Collator: `{}`\n\
-            Available: {}\n\
+            Available: {}{}\n\
             Required: {}\n\n\
             Please add funds to continue as a candidate.{}\n\n\
-            _This alert is rate-limited to once every 4 hours._",
-            chain_name, collator_address, available, required, mentions
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if the shortfall changes, \
+            otherwise backs off exponentially up to 4 hours._",
+            chain_name, collator_address, available, locked_line, required, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
         );
 
-        self.send(&message).await
+        self.record_alert("insufficient_funds");
+        self.send_with_severity(Severity::Critical, &message).await
     }
 
     /// Send an alert requiring manual action (rate limited)
@@ -735,13 +1023,14 @@ impl SlackNotifier {
         self.mark_manual_action_required(chain_name);
 
         let rate_key = format!("{}:manual_action", chain_name);
-        if !self.should_notify(&rate_key) {
+        let payload_hash = Self::payload_hash(&[&action_description, &call_data.unwrap_or_default()]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
             info!("Rate limited: manual action alert for {}", chain_name);
             return Ok(());
-        }
+        };
 
         let mentions = self.format_onchain_mentions();
-        
+
         let call_data_section = if let Some(data) = call_data {
             format!(
                 "\n\n*Batch Call Data* (for Polkadot.js Developer > Extrinsics > Decode):\n```{}```",
@@ -758,11 +1047,14 @@ impl SlackNotifier {
             Automatic action not possible on this chain.\n\
             *Action needed:* {}{}\n\n\
             Please perform this action manually via Polkadot.js or similar.{}\n\n\
-            _This alert is rate-limited to once every 4 hours._",
-            chain_name, collator_address, action_description, call_data_section, mentions
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if the required action \
+            changes, otherwise backs off exponentially up to 4 hours._",
+            chain_name, collator_address, action_description, call_data_section, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
         );
 
-        self.send(&message).await
+        self.record_alert("manual_action_required");
+        self.send_with_severity(Severity::Critical, &message).await
     }
 
     /// Notify about successful registration
@@ -788,6 +1080,33 @@ impl SlackNotifier {
         self.send(&message).await
     }
 
+    /// Notify about successful registration that displaced the previous lowest candidate
+    pub async fn notify_registration_by_eviction(
+        &self,
+        chain_name: &str,
+        collator_address: &str,
+        bond_amount: u128,
+        evicted_address: &str,
+        evicted_bond: u128,
+        token_symbol: &str,
+        decimals: u32,
+    ) -> Result<()> {
+        self.remove_outstanding_issue(chain_name);
+        self.clear_manual_action_required(chain_name);
+
+        let bond = format_balance(bond_amount, decimals, token_symbol);
+        let evicted = format_balance(evicted_bond, decimals, token_symbol);
+        let message = format!(
+            "‚úÖ *Registered as candidate* on *{}* (by eviction)\n\n\
+            Collator: `{}`\n\
+            Bond: {}\n\
+            Displaced: `{}` (bond: {})",
+            chain_name, collator_address, bond, evicted_address, evicted
+        );
+
+        self.send(&message).await
+    }
+
     /// Notify about bond update
     pub async fn notify_bond_update(
         &self,
@@ -811,6 +1130,32 @@ impl SlackNotifier {
         self.send(&message).await
     }
 
+    /// Notify about a bond top-up triggered by rank-based maintenance (the collator
+    /// had slipped toward the cutoff of a full candidate pool, not a routine update)
+    pub async fn notify_bond_rebalanced(
+        &self,
+        chain_name: &str,
+        collator_address: &str,
+        old_bond: u128,
+        new_bond: u128,
+        rank: usize,
+        token_symbol: &str,
+        decimals: u32,
+    ) -> Result<()> {
+        let old = format_balance(old_bond, decimals, token_symbol);
+        let new = format_balance(new_bond, decimals, token_symbol);
+        let message = format!(
+            "⚖️ *Bond Rebalanced* on *{}*\n\n\
+            Collator: `{}`\n\
+            Rank before top-up: #{}\n\
+            Previous: {}\n\
+            New: {}",
+            chain_name, collator_address, rank, old, new
+        );
+
+        self.send(&message).await
+    }
+
     /// Notify that an issue was resolved (detected by change in status)
     pub async fn notify_issue_resolved(
         &self,
@@ -877,7 +1222,25 @@ impl SlackNotifier {
                 String::new()
             };
 
-            lines.push(format!("‚Ä¢ *{}*: {}{}", slot.chain_name, status, block_time));
+            let reward_str = if slot.is_invulnerable || slot.is_candidate {
+                format!(
+                    " | Rewards: {} total (+{} since last)",
+                    format_balance(slot.total_rewards_observed, slot.decimals, &slot.token_symbol),
+                    format_balance(slot.reward_delta_since_last, slot.decimals, &slot.token_symbol)
+                )
+            } else {
+                String::new()
+            };
+
+            let session_str = match (slot.current_session_index, slot.blocks_until_next_rotation) {
+                (Some(index), Some(blocks_left)) => {
+                    format!(" | Session {} (~{} blocks to next rotation)", index, blocks_left)
+                }
+                (Some(index), None) => format!(" | Session {}", index),
+                (None, _) => String::new(),
+            };
+
+            lines.push(format!("‚Ä¢ *{}*: {}{}{}{}", slot.chain_name, status, block_time, reward_str, session_str));
         }
 
         let message = lines.join("\n");
@@ -890,41 +1253,492 @@ impl SlackNotifier {
             "‚ùå *Error* on *{}*\n\n`{}`",
             chain_name, error
         );
-        self.send(&message).await
+        self.send_with_severity(Severity::Warning, &message).await
     }
 
-    /// Alert that we cannot compete (bond too low)
-    pub async fn alert_cannot_compete(
+    /// Alert that the candidate pool is full and our bond can't clear the
+    /// threshold needed to evict the lowest-bonded candidate
+    pub async fn alert_not_competitive(
         &self,
         chain_name: &str,
         collator_address: &str,
         available_balance: u128,
-        lowest_bond: u128,
-        needed: u128,
+        required_bond: u128,
         token_symbol: &str,
         decimals: u32,
     ) -> Result<()> {
         self.add_outstanding_issue(chain_name);
 
-        let rate_key = format!("{}:cannot_compete", chain_name);
-        if !self.should_notify(&rate_key) {
+        let rate_key = format!("{}:not_competitive", chain_name);
+        let payload_hash = Self::payload_hash(&[&available_balance, &required_bond]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
             return Ok(());
-        }
+        };
 
         let available = format_balance(available_balance, decimals, token_symbol);
-        let lowest = format_balance(lowest_bond, decimals, token_symbol);
-        let need = format_balance(needed, decimals, token_symbol);
+        let required = format_balance(required_bond, decimals, token_symbol);
         let mentions = self.format_onchain_mentions();
 
         let message = format!(
-            "‚ö†Ô∏è *Cannot Compete* on *{}*\n\n\
+            "‚ö†Ô∏è *Not Competitive* on *{}*\n\n\
             Collator: `{}`\n\
             Available: {}\n\
-            Lowest candidate bond: {}\n\
-            Need at least: {} more\n\n\
-            Please add funds to register as a candidate.{}\n\n\
-            _This alert is rate-limited to once every 4 hours._",
-            chain_name, collator_address, available, lowest, need, mentions
+            Required to clear pool threshold: {}\n\n\
+            Candidate pool is full - please add funds to register as a candidate.{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if the shortfall changes, \
+            otherwise backs off exponentially up to 4 hours._",
+            chain_name, collator_address, available, required, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("not_competitive");
+        self.send_with_severity(Severity::Critical, &message).await
+    }
+
+    /// Alert that a collator in the active set has gone quiet well past its
+    /// expected authoring cadence (rate limited)
+    pub async fn alert_delinquent(
+        &self,
+        chain_name: &str,
+        slots_missed: u64,
+        expected_interval_secs: u64,
+    ) -> Result<()> {
+        self.add_outstanding_issue(chain_name);
+
+        let rate_key = format!("{}:delinquent", chain_name);
+        let payload_hash = Self::payload_hash(&[&slots_missed]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: delinquency alert for {}", chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "🐢 *Collator Delinquent* on *{}*\n\n\
+            No block authored in roughly *{}* expected slot(s) (expected every ~{}s).\n\
+            Node may have stalled, lost peers, or lost its session keys.{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if slots missed grows, \
+            otherwise backs off exponentially up to 4 hours._",
+            chain_name, slots_missed, expected_interval_secs, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("delinquent");
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Block-height equivalent of [`Self::alert_delinquent`], modeled on Solana's
+    /// slot-distance liveness checks: maintains its own per-chain map of the last
+    /// observed authored block (seeded, not alerted, the first time a chain is
+    /// seen so a process restart never looks like a fresh delinquency) and fires
+    /// an escalating [`Self::alert_collator_delinquent`] once `slots_since_authored`
+    /// clears `threshold_slots`. A newly (re)joined active collator - including one
+    /// that just became invulnerable - gets [`DELINQUENCY_GRACE_PERIOD`] before it's
+    /// eligible to be flagged. Call once per poll with the latest [`ChainSlotInfo`].
+    pub async fn check_collator_delinquency(
+        &self,
+        slot: &ChainSlotInfo,
+        collator_address: &str,
+        threshold_slots: u64,
+    ) -> Result<()> {
+        let is_active = slot.is_invulnerable || slot.is_candidate;
+
+        let progress = {
+            let mut tracking = self.delinquency_tracking.lock().unwrap();
+            match tracking.get_mut(&slot.chain_name) {
+                None => {
+                    tracking.insert(
+                        slot.chain_name.clone(),
+                        DelinquencyTracking {
+                            last_authored_block: slot.last_authored_block,
+                            was_active: is_active,
+                            active_since: Instant::now(),
+                        },
+                    );
+                    None
+                }
+                Some(tracked) => {
+                    if is_active && !tracked.was_active {
+                        tracked.active_since = Instant::now();
+                    }
+                    tracked.was_active = is_active;
+
+                    let made_progress = match (slot.last_authored_block, tracked.last_authored_block) {
+                        (Some(current), Some(previous)) => current > previous,
+                        (Some(_), None) => true,
+                        _ => false,
+                    };
+                    if made_progress {
+                        tracked.last_authored_block = slot.last_authored_block;
+                    }
+
+                    Some((made_progress, tracked.active_since))
+                }
+            }
+        };
+
+        let Some((made_progress, active_since)) = progress else {
+            // First time this chain has been seen - tracking seeded, nothing to alert on yet.
+            return Ok(());
+        };
+
+        if made_progress {
+            return self
+                .notify_issue_resolved(&slot.chain_name, collator_address, "authoring blocks normally")
+                .await;
+        }
+
+        if !is_active || active_since.elapsed() < DELINQUENCY_GRACE_PERIOD {
+            return Ok(());
+        }
+
+        let Some(last_authored) = slot.last_authored_block else {
+            return Ok(()); // never authored yet - no block to measure distance from
+        };
+
+        let slots_behind = slot.current_block.saturating_sub(last_authored);
+        if slots_behind < threshold_slots {
+            return Ok(());
+        }
+
+        self.alert_collator_delinquent(&slot.chain_name, collator_address, slots_behind, threshold_slots)
+            .await
+    }
+
+    /// Alert that a collator in the active set is `slots_behind` blocks past its
+    /// last authored block, which exceeds `expected_slots`. Escalates from WARN to
+    /// CRITICAL at `expected_slots * DELINQUENCY_CRITICAL_MULTIPLIER`, and rate-limits
+    /// each severity tier under its own key so a CRITICAL escalation is never
+    /// suppressed by an earlier WARN having just fired.
+    pub async fn alert_collator_delinquent(
+        &self,
+        chain_name: &str,
+        collator_address: &str,
+        slots_behind: u64,
+        expected_slots: u64,
+    ) -> Result<()> {
+        self.add_outstanding_issue(chain_name);
+
+        let critical_threshold = expected_slots.saturating_mul(DELINQUENCY_CRITICAL_MULTIPLIER);
+        let (severity, label, emoji, rate_key_suffix) = if slots_behind >= critical_threshold {
+            (Severity::Critical, "CRITICAL", "🔴", "delinquent_collator:critical")
+        } else {
+            (Severity::Warning, "WARN", "🟡", "delinquent_collator:warn")
+        };
+
+        let rate_key = format!("{}:{}", chain_name, rate_key_suffix);
+        let payload_hash = Self::payload_hash(&[&slots_behind]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: {} delinquent-collator alert for {}", label, chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "{} *{} - Collator Delinquent* on *{}*\n\n\
+            Collator: `{}`\n\
+            *{}* slots since last authored block (expected within ~{}).{}\n\n\
+            _Ongoing for {} ({} occurrence(s)) at this severity tier. Re-alerts immediately \
+            if slots behind grows, otherwise backs off exponentially up to 4 hours._",
+            emoji, label, chain_name, collator_address, slots_behind, expected_slots, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("collator_delinquent");
+        self.send_with_severity(severity, &message).await
+    }
+
+    /// Warn that a collator is steadily dropping its own scheduled Aura slots even
+    /// though it's still authoring *some* blocks, so [`Self::alert_collator_delinquent`]
+    /// hasn't tripped. `expected`/`actual` count our scheduled slots over the
+    /// [`crate::block_tracker::BlockTracker`] sliding window; `miss_rate` is `1 - actual/expected`.
+    pub async fn alert_missed_slots(
+        &self,
+        chain_name: &str,
+        expected: u32,
+        actual: u32,
+        miss_rate: f64,
+    ) -> Result<()> {
+        self.add_outstanding_issue(chain_name);
+
+        let rate_key = format!("{}:missed_slots", chain_name);
+        let payload_hash = Self::payload_hash(&[&expected, &actual]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: missed-slot alert for {}", chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "🟡 *Missed Scheduled Slots* on *{}*\n\n\
+            Authored *{}* of *{}* expected Aura slots over the tracking window (*{:.0}%* missed).{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if the miss rate grows, \
+            otherwise backs off exponentially up to 4 hours._",
+            chain_name, actual, expected, miss_rate * 100.0, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("missed_slots");
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Critical alert, distinct from [`Self::alert_missed_slots`]'s routine warning,
+    /// for a collator that's still in the active set but has effectively stopped
+    /// producing - `miss_rate` at or above the severe skip-rate threshold rather
+    /// than just elevated.
+    pub async fn alert_severe_skip_rate(
+        &self,
+        chain_name: &str,
+        authored: u32,
+        expected: u32,
+        miss_rate: f64,
+    ) -> Result<()> {
+        self.add_outstanding_issue(chain_name);
+
+        let rate_key = format!("{}:severe_skip_rate", chain_name);
+        let payload_hash = Self::payload_hash(&[&expected, &authored]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: severe skip-rate alert for {}", chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "🔴 *CRITICAL - Collator Not Producing* on *{}*\n\n\
+            In the active set but authored only *{}* of *{}* expected Aura slots \
+            (*{:.0}%* skipped) - check session keys and node health.{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if the skip rate \
+            grows, otherwise backs off exponentially up to 4 hours._",
+            chain_name, authored, expected, miss_rate * 100.0, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("severe_skip_rate");
+        self.send_with_severity(Severity::Critical, &message).await
+    }
+
+    /// Alert that a chain's background tracker task panicked or exited
+    /// unexpectedly and is being restarted with exponential backoff. Not
+    /// rate limited beyond the backoff itself, since each call already
+    /// implies strictly increasing spacing between attempts.
+    pub async fn alert_tracker_restart(
+        &self,
+        chain_name: &str,
+        reason: &str,
+        attempt: u32,
+        backoff: Duration,
+    ) -> Result<()> {
+        self.add_outstanding_issue(chain_name);
+
+        let message = format!(
+            "♻️ *Chain Tracker Restarting* on *{}*\n\n\
+            The background block tracker {} and is being restarted (attempt *{}*, retrying in *{}*).\n\
+            Block authorship, delinquency, slot-miss and channel-backlog monitoring for this chain \
+            was briefly interrupted.",
+            chain_name, reason, attempt, Self::format_duration(backoff)
+        );
+
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Warn that an HRMP/XCMP channel's queued messages/bytes have crossed a
+    /// high-water mark, or sat non-draining too long, risking stalled cross-chain
+    /// transfers. Rate limited per channel (`chain:channel_backlog:<direction>:<para_id>`).
+    pub async fn alert_channel_backlog(
+        &self,
+        chain_name: &str,
+        direction: &str,
+        para_id: u32,
+        msg_count: u32,
+        total_size: u32,
+    ) -> Result<()> {
+        let rate_key = format!("{}:channel_backlog:{}:{}", chain_name, direction, para_id);
+        let payload_hash = Self::payload_hash(&[&msg_count, &total_size]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: channel backlog alert for {} {} channel to para {}", chain_name, direction, para_id);
+            return Ok(());
+        };
+
+        let message = format!(
+            "📬 *Channel Backlog* on *{}*\n\n\
+            {} HRMP/XCMP channel to para *{}* has *{}* queued message(s) totaling *{}* bytes.\n\
+            This can stall cross-chain transfers until it drains.\n\n\
+            _Ongoing for {} ({} occurrence(s)). Backs off exponentially up to 4 hours._",
+            chain_name, direction, para_id, msg_count, total_size,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("channel_backlog");
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Clear a previously alerted channel backlog once it's back under the
+    /// high-water mark and draining normally.
+    pub async fn clear_channel_backlog(&self, chain_name: &str, direction: &str, para_id: u32) -> Result<()> {
+        let rate_key = format!("{}:channel_backlog:{}:{}", chain_name, direction, para_id);
+        if self.rate_limit_state.lock().unwrap().remove(&rate_key).is_none() {
+            return Ok(()); // never alerted - nothing to clear
+        }
+
+        let message = format!(
+            "✅ *Channel Backlog Cleared* on *{}*\n\n{} channel to para {} has drained below the high-water mark.",
+            chain_name, direction, para_id
+        );
+        self.send(&message).await
+    }
+
+    /// Warn that a BridgeHub's default message lane has an outbound backlog
+    /// (messages generated but not yet confirmed delivered by the counterpart
+    /// chain) past the configured threshold. Rate limited per chain.
+    pub async fn alert_bridge_lane_backlog(
+        &self,
+        chain_name: &str,
+        backlog: u64,
+        threshold: u64,
+    ) -> Result<()> {
+        let rate_key = format!("{}:bridge_lane_backlog", chain_name);
+        let payload_hash = Self::payload_hash(&[&backlog]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: bridge lane backlog alert for {}", chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "🌉 *Bridge Lane Backlog* on *{}*\n\n\
+            The default message lane's outbound backlog is *{}* message(s), \
+            past the configured threshold of *{}*. The relay delivering messages \
+            to the counterpart chain may have stopped or fallen behind.{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Backs off exponentially up to 4 hours._",
+            chain_name, backlog, threshold, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("bridge_lane_backlog");
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Clear a previously alerted bridge lane backlog once it's back under
+    /// the threshold.
+    pub async fn clear_bridge_lane_backlog(&self, chain_name: &str) -> Result<()> {
+        let rate_key = format!("{}:bridge_lane_backlog", chain_name);
+        if self.rate_limit_state.lock().unwrap().remove(&rate_key).is_none() {
+            return Ok(()); // never alerted - nothing to clear
+        }
+
+        let message = format!(
+            "✅ *Bridge Lane Backlog Cleared* on *{}*\n\nThe default message lane has drained below the threshold.",
+            chain_name
+        );
+        self.send(&message).await
+    }
+
+    /// Warn that a BridgeHub's GRANDPA finality relay hasn't advanced the
+    /// counterpart chain's recorded best-finalized header across several
+    /// consecutive check cycles. Rate limited per chain.
+    pub async fn alert_bridge_relay_stalled(
+        &self,
+        chain_name: &str,
+        stalled_cycles: u32,
+        best_finalized: u32,
+    ) -> Result<()> {
+        let rate_key = format!("{}:bridge_relay_stalled", chain_name);
+        let payload_hash = Self::payload_hash(&[&stalled_cycles]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: bridge relay stalled alert for {}", chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "🌉 *Bridge Finality Relay Stalled* on *{}*\n\n\
+            The counterpart chain's best-finalized header has stayed at block *{}* \
+            for *{}* consecutive check cycle(s). The GRANDPA finality relay may have \
+            stopped.{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Backs off exponentially up to 4 hours._",
+            chain_name, best_finalized, stalled_cycles, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("bridge_relay_stalled");
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Clear a previously alerted stalled finality relay once it's advanced again.
+    pub async fn clear_bridge_relay_stalled(&self, chain_name: &str) -> Result<()> {
+        let rate_key = format!("{}:bridge_relay_stalled", chain_name);
+        if self.rate_limit_state.lock().unwrap().remove(&rate_key).is_none() {
+            return Ok(()); // never alerted - nothing to clear
+        }
+
+        let message = format!(
+            "✅ *Bridge Finality Relay Recovered* on *{}*\n\nThe counterpart chain's best-finalized header is advancing again.",
+            chain_name
+        );
+        self.send(&message).await
+    }
+
+    /// Alert that an active collator has shown zero reward accrual across several
+    /// consecutive summary intervals (rate limited)
+    pub async fn alert_no_reward_accrual(
+        &self,
+        chain_name: &str,
+        consecutive_intervals: u32,
+    ) -> Result<()> {
+        self.add_outstanding_issue(chain_name);
+
+        let rate_key = format!("{}:no_reward_accrual", chain_name);
+        let payload_hash = Self::payload_hash(&[&consecutive_intervals]);
+        let Some(occurrence) = self.should_notify(&rate_key, payload_hash) else {
+            info!("Rate limited: no-reward-accrual alert for {}", chain_name);
+            return Ok(());
+        };
+
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "üí∏ *No Reward Accrual* on *{}*\n\n\
+            This collator has shown zero reward accrual across the last *{}* summary intervals \
+            despite holding a slot. It may be authoring blocks without being rewarded, or not \
+            authoring at all.{}\n\n\
+            _Ongoing for {} ({} occurrence(s)). Re-alerts immediately if the streak grows, \
+            otherwise backs off exponentially up to 4 hours._",
+            chain_name, consecutive_intervals, mentions,
+            Self::format_duration(occurrence.ongoing_for), occurrence.occurrence_count
+        );
+
+        self.record_alert("no_reward_accrual");
+        self.send_with_severity(Severity::Warning, &message).await
+    }
+
+    /// Notify that a reward payout was observed for a collator. Not rate-limited like
+    /// the alert_* methods above - callers are expected to filter out dust themselves
+    /// (see `REWARD_DUST_THRESHOLD_DIVISOR` in monitor.rs) so every call here already
+    /// represents a payout worth telling someone about.
+    pub async fn notify_reward_payout(
+        &self,
+        chain_name: &str,
+        collator_address: &str,
+        delta: u128,
+        cumulative: u128,
+        token_symbol: &str,
+        decimals: u32,
+    ) -> Result<()> {
+        let delta_str = format_balance(delta, decimals, token_symbol);
+        let cumulative_str = format_balance(cumulative, decimals, token_symbol);
+
+        let message = format!(
+            "üí∞ *Reward Payout* on *{}*\n\n\
+            Collator: `{}`\n\
+            Received: {}\n\
+            Cumulative observed: {}",
+            chain_name, collator_address, delta_str, cumulative_str
         );
 
         self.send(&message).await
@@ -941,6 +1755,22 @@ impl SlackNotifier {
     ) -> Result<()> {
         self.notify_registration(chain_name, collator_address, bond_amount, token_symbol, decimals).await
     }
+
+    /// Final status message posted as the monitor shuts down, so ops has a
+    /// clear record of why it stopped instead of the process just going quiet.
+    /// Not rate limited - this is a one-shot notice, not a recurring alert.
+    pub async fn notify_monitor_stopping(&self, reason: &str) -> Result<()> {
+        let mentions = self.format_ops_mentions();
+
+        let message = format!(
+            "🛑 *Monitor Stopping*\n\n{}{}\n\n\
+            Any already-submitted transactions were left to reach finalization; \
+            no new registrations or bond updates were started after shutdown began.",
+            reason, mentions
+        );
+
+        self.send_with_severity(Severity::Warning, &message).await
+    }
 }
 
 /// Format a balance with proper decimals
@@ -0,0 +1,103 @@
+//! SS58Check encoding/decoding for `AccountId32`.
+//!
+//! `subxt`'s own `AccountId32` `Display`/`FromStr` impls bake in a single
+//! fixed network prefix, which doesn't fit a monitor that talks to both
+//! Polkadot (prefix 0) and Kusama (prefix 2) - addresses parsed or printed
+//! through them silently assume one network's prefix regardless of which
+//! chain they actually came from. This implements the SS58Check algorithm
+//! directly, parameterized on `prefix`, so collator/authority sets pulled off
+//! either chain can be printed and filtered by their own network's address
+//! form.
+//!
+//! Algorithm: `blake2b_512(b"SS58PRE" || prefix_bytes || account)`, of which
+//! the first 2 bytes become a checksum appended after the account bytes, and
+//! the whole thing (`prefix_bytes || account || checksum`) is Base58-encoded.
+//! `prefix` 0-63 is a single byte; 64-16383 is split across two bytes per the
+//! SS58 spec.
+
+use blake2::{Blake2b512, Digest};
+use subxt::utils::AccountId32;
+
+use crate::error::CollatorError;
+
+const CHECKSUM_PREFIX: &[u8] = b"SS58PRE";
+const CHECKSUM_LEN: usize = 2;
+const ACCOUNT_LEN: usize = 32;
+
+/// Encode `account` as an SS58Check string under `prefix` (e.g. 0 for
+/// Polkadot, 2 for Kusama, 42 for the generic substrate prefix).
+pub fn to_ss58(account: &AccountId32, prefix: u16) -> String {
+    let mut payload = prefix_bytes(prefix);
+    payload.extend_from_slice(&account.0);
+    let checksum = ss58_checksum(&payload);
+    payload.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+    bs58::encode(payload).into_string()
+}
+
+/// Decode an SS58Check string, returning the account and the network prefix
+/// it was encoded under. Rejects malformed Base58, wrong-length payloads, and
+/// a checksum mismatch.
+pub fn from_ss58(s: &str) -> Result<(AccountId32, u16), CollatorError> {
+    let data = bs58::decode(s)
+        .into_vec()
+        .map_err(|e| CollatorError::InvalidAddress(format!("{}: bad base58 ({})", s, e)))?;
+
+    if data.len() < 2 {
+        return Err(CollatorError::InvalidAddress(format!("{}: too short to be a valid SS58 address", s)));
+    }
+
+    let (prefix_len, prefix) = match data[0] {
+        0..=63 => (1usize, data[0] as u16),
+        64..=127 => {
+            let lower = (data[0] << 2) | (data[1] >> 6);
+            let upper = data[1] & 0b0011_1111;
+            (2usize, (lower as u16) | ((upper as u16) << 8))
+        }
+        _ => return Err(CollatorError::InvalidAddress(format!("{}: unsupported SS58 prefix byte", s))),
+    };
+
+    if data.len() != prefix_len + ACCOUNT_LEN + CHECKSUM_LEN {
+        return Err(CollatorError::InvalidAddress(format!(
+            "{}: expected {} bytes, got {}",
+            s,
+            prefix_len + ACCOUNT_LEN + CHECKSUM_LEN,
+            data.len()
+        )));
+    }
+
+    let body_end = prefix_len + ACCOUNT_LEN;
+    let checksum = ss58_checksum(&data[..body_end]);
+    if data[body_end..body_end + CHECKSUM_LEN] != checksum[..CHECKSUM_LEN] {
+        return Err(CollatorError::InvalidAddress(format!("{}: checksum mismatch", s)));
+    }
+
+    let mut bytes = [0u8; ACCOUNT_LEN];
+    bytes.copy_from_slice(&data[prefix_len..body_end]);
+    Ok((AccountId32(bytes), prefix))
+}
+
+/// Single- or two-byte SS58 prefix encoding, per the spec's bit-splitting for
+/// prefixes above 63.
+fn prefix_bytes(prefix: u16) -> Vec<u8> {
+    match prefix {
+        0..=63 => vec![prefix as u8],
+        64..=16_383 => {
+            let first = ((prefix & 0b0000_0000_1111_1100) as u8) >> 2;
+            let second = ((prefix >> 8) as u8) | (((prefix & 0b0000_0000_0000_0011) as u8) << 6);
+            vec![first | 0b0100_0000, second]
+        }
+        // Out of the 14-bit range the spec allows - fall back to the generic
+        // substrate prefix rather than producing an address no chain uses.
+        _ => vec![42u8],
+    }
+}
+
+fn ss58_checksum(payload: &[u8]) -> [u8; 64] {
+    let mut hasher = Blake2b512::new();
+    hasher.update(CHECKSUM_PREFIX);
+    hasher.update(payload);
+    let result = hasher.finalize();
+    let mut out = [0u8; 64];
+    out.copy_from_slice(&result);
+    out
+}